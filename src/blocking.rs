@@ -1,111 +1,304 @@
-use std::cmp::Ordering;
+use std::{
+    io::Read,
+    ops::ControlFlow,
+    time::{Duration, Instant},
+};
 
-use reqwest::StatusCode;
-use time::OffsetDateTime;
+use reqwest::{header::ACCEPT_LANGUAGE, StatusCode};
+use serde::de::DeserializeOwned;
 
-use crate::{Articles, Content, Error, TDate, TRequestBuilder, TextArticle, Timeframe, Video};
+use crate::{
+    check_content_type, collected, log_unknown_fields, parse_merged, parse_response, prioritize,
+    request_error, retain_matching, retain_recent, split, Articles, Content, Error, ErrorMode,
+    TDate, TRequestBuilder, TextArticle, Video,
+};
 
 impl TRequestBuilder {
     fn fetch_blocking(&self, date: TDate) -> Result<Articles, Error> {
+        let text = self.fetch_text_blocking(date)?;
+        if !self.strict_deserialization {
+            log_unknown_fields(&text);
+        }
+
+        let mut articles: Articles = parse_response(&text)?;
+
+        articles.assign_order();
+
+        Ok(articles)
+    }
+
+    fn fetch_text_blocking(&self, date: TDate) -> Result<String, Error> {
         let url = self.prepare_url(date)?;
 
-        let response = reqwest::blocking::get(url).map_err(|e| Error::BadRequest(e))?;
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
-        let text = match response.status() {
-            StatusCode::OK => response.text().map_err(|e| Error::ParsingError(e))?,
-            _ => Err(Error::InvalidResponse(response.status().as_u16()))?,
-        };
+        let result = self.get_blocking(url);
 
-        let articles: Articles = serde_json::from_str(&text)?;
+        #[cfg(feature = "metrics")]
+        self.record_metrics(&result, start.elapsed());
 
-        Ok(articles)
+        result
+    }
+
+    fn blocking_client(&self) -> Result<reqwest::blocking::Client, Error> {
+        let mut builder = reqwest::blocking::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(max) = self.max_redirects {
+            builder = builder.redirect(reqwest::redirect::Policy::limited(max));
+        }
+
+        builder.build().map_err(Error::ClientError)
+    }
+
+    fn get_blocking(&self, url: String) -> Result<String, Error> {
+        let mut request = self
+            .blocking_client()?
+            .get(url)
+            .headers(self.headers.clone());
+
+        if let Some(lang) = &self.accept_language {
+            request = request.header(ACCEPT_LANGUAGE, lang);
+        }
+
+        let response = request.send().map_err(request_error)?;
+
+        match response.status() {
+            StatusCode::OK => {
+                check_content_type(response.headers())?;
+
+                read_body_blocking(response, self.max_response_bytes)
+            }
+            _ => Err(Error::InvalidResponse(response.status().as_u16())),
+        }
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
     /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content] as a blocking request.
     pub fn get_all_articles_blocking(&self) -> Result<Vec<Content>, Error> {
-        let dates: Vec<TDate> = match &self.timeframe {
-            Timeframe::Now => {
-                let now = OffsetDateTime::now_local()?;
+        self.group(self.all_articles_blocking())
+    }
+
+    /// Queries the given dates one after another and passes each successful result to `handle`, until it breaks.
+    ///
+    /// This is the blocking counterpart of `for_each_date` and validates the parameters and honors the deadline, error
+    /// mode and progress callback of the `TRequestBuilder` the same way.
+    fn for_each_date_blocking<T>(
+        &self,
+        dates: Vec<TDate>,
+        fetch: impl Fn(TDate) -> Result<T, Error>,
+        mut handle: impl FnMut(TDate, T) -> ControlFlow<()>,
+    ) -> Result<Vec<Error>, Error> {
+        self.validate()?;
+
+        let mut errors: Vec<Error> = Vec::new();
 
-                vec![TDate::from_time_date(now.date())]
+        let deadline = self.deadline.map(|deadline| Instant::now() + deadline);
+
+        let total = dates.len();
+
+        for (done, date) in dates.into_iter().enumerate() {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                match self.error_mode {
+                    ErrorMode::Collect => {
+                        errors.push(Error::DeadlineExceeded);
+                        break;
+                    }
+                    ErrorMode::FailFast => return Err(Error::DeadlineExceeded),
+                }
             }
-            Timeframe::Date(date) => {
-                vec![*date]
+
+            let result = fetch(date);
+
+            if let Some(on_progress) = &self.on_progress {
+                on_progress(done + 1, total, date);
             }
-            Timeframe::DateRange(date_range) => {
-                Vec::from_iter(date_range.dates.clone().into_iter())
+
+            match result {
+                Ok(value) => {
+                    if handle(date, value).is_break() {
+                        break;
+                    }
+                }
+                Err(e) if self.error_mode == ErrorMode::Collect => errors.push(e),
+                Err(e) => return Err(e),
             }
-        };
+        }
+
+        Ok(errors)
+    }
 
+    fn all_articles_blocking(&self) -> Result<Vec<Content>, Error> {
         let mut content: Vec<Content> = Vec::new();
 
-        for date in dates {
-            let mut art = self.fetch_blocking(date)?;
+        let errors = self.for_each_date_blocking(
+            self.dates()?,
+            |date| self.fetch_blocking(date),
+            |_, art| {
+                content.append(&mut art.into_content());
+
+                ControlFlow::Continue(())
+            },
+        )?;
+
+        self.finish(content, errors)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of
+    /// [Content], querying up to `concurrency` dates at the same time, as a blocking request.
+    ///
+    /// The requests are driven by a single-threaded tokio runtime that is created for this call and shut down
+    /// afterwards. Its blocking thread pool is still used for DNS resolution, so a few short-lived threads may be
+    /// spawned. This must not be called from within an async runtime.
+    pub fn get_all_articles_concurrent_blocking(
+        &self,
+        concurrency: usize,
+    ) -> Result<Vec<Content>, Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        runtime.block_on(async {
+            let dates = self.dates()?;
+
+            let (content, _) = self.fetch_dates_concurrently(dates, concurrency).await?;
 
-            content.append(&mut art.news)
+            Ok(content)
+        })
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object,
+    /// deserializing each article into a custom type `T`, as a blocking request.
+    ///
+    /// See [`get_all_as`](TRequestBuilder::get_all_as) for the requirements on `T`.
+    pub fn get_all_as_blocking<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error> {
+        let mut items: Vec<T> = Vec::new();
+
+        let errors = self.for_each_date_blocking(
+            self.dates()?,
+            |date| parse_merged::<T>(&self.fetch_text_blocking(date)?),
+            |_, mut art| {
+                items.append(&mut art);
+
+                ControlFlow::Continue(())
+            },
+        )?;
+
+        collected(items, errors)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content],
+    /// returning [`Error::NoResults`] instead of an empty result, as a blocking request.
+    pub fn get_all_articles_nonempty_blocking(&self) -> Result<Vec<Content>, Error> {
+        let content = self.all_articles_blocking()?;
+
+        if content.is_empty() {
+            return Err(Error::NoResults {
+                dates: self.dates()?,
+                filters: self.filters(),
+            });
         }
 
-        content.sort_by(|element, next| {
-            let date_element = match element {
-                Content::TextArticle(t) => t.date,
-                Content::Video(v) => v.date,
-            };
-
-            let date_next = match next {
-                Content::TextArticle(t) => t.date,
-                Content::Video(v) => v.date,
-            };
-
-            if date_element > date_next {
-                Ordering::Greater
-            } else if date_element < date_next {
-                Ordering::Less
-            } else {
-                Ordering::Equal
-            }
-        });
+        Ok(content)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content],
+    /// with breaking news first and the remaining articles ordered from newest to oldest, as a blocking request.
+    pub fn get_all_articles_prioritized_blocking(&self) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles_blocking()?;
+
+        prioritize(&mut content);
 
         Ok(content)
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
-    /// Query only [`TextArticle`] articles that match the parameters currently specified on the `TRequestBuilder` Object as a blocking request.
-    pub fn get_text_articles_blocking(&self) -> Result<Vec<TextArticle>, Error> {
-        let articles = self.get_all_articles_blocking();
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content]
+    /// and contain `query` in their title, topline or first sentence, ignoring case, as a blocking request.
+    pub fn search_blocking(&self, query: &str) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles_blocking()?;
 
-        match articles {
-            Ok(mut a) => {
-                a.retain(|x| x.is_text());
-                let mut t: Vec<TextArticle> = Vec::new();
+        retain_matching(&mut content, query);
 
-                for content in a {
-                    t.push(content.to_text().unwrap())
-                }
+        Ok(content)
+    }
 
-                Ok(t)
-            }
-            Err(e) => Err(e),
-        }
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content]
+    /// and were published within the given duration before now, as a blocking request.
+    pub fn get_recent_blocking(&self, within: Duration) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles_blocking()?;
+
+        retain_recent(&mut content, within);
+
+        Ok(content)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object, split into
+    /// [`TextArticles`](TextArticle) and [`Videos`](Video), as a blocking request.
+    ///
+    /// See [`get_split`](TRequestBuilder::get_split).
+    pub fn get_split_blocking(&self) -> Result<(Vec<TextArticle>, Vec<Video>), Error> {
+        let articles = self.all_articles_blocking()?;
+
+        Ok(split(articles))
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    /// Query only [`TextArticle`] articles that match the parameters currently specified on the `TRequestBuilder` Object as a blocking request.
+    pub fn get_text_articles_blocking(&self) -> Result<Vec<TextArticle>, Error> {
+        let articles = self.all_articles_blocking()?;
+
+        Ok(articles
+            .into_iter()
+            .filter_map(|content| content.to_text().ok())
+            .collect())
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
     /// Query only [`Videos`](Video) that match the parameters currently specified on the `TRequestBuilder` Object as a blocking request.
     pub fn get_video_articles_blocking(&self) -> Result<Vec<Video>, Error> {
-        let articles = self.get_all_articles_blocking();
+        let articles = self.all_articles_blocking()?;
 
-        match articles {
-            Ok(mut a) => {
-                a.retain(|x| x.is_video());
-                let mut t: Vec<Video> = Vec::new();
+        Ok(articles
+            .into_iter()
+            .filter_map(|content| content.to_video().ok())
+            .collect())
+    }
+}
 
-                for content in a {
-                    t.push(content.to_video().unwrap())
-                }
+/// Reads the response body, aborting as soon as it exceeds `limit` bytes.
+fn read_body_blocking(
+    response: reqwest::blocking::Response,
+    limit: usize,
+) -> Result<String, Error> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > limit as u64)
+    {
+        return Err(Error::ResponseTooLarge(limit));
+    }
 
-                Ok(t)
-            }
-            Err(e) => Err(e),
-        }
+    let mut body: Vec<u8> = Vec::new();
+
+    response.take(limit as u64 + 1).read_to_end(&mut body)?;
+
+    if body.len() > limit {
+        return Err(Error::ResponseTooLarge(limit));
     }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
 }
@@ -4,23 +4,56 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 
-use reqwest;
-
-use reqwest::StatusCode;
-use serde::{de, Deserialize, Deserializer};
+use async_trait::async_trait;
+use futures::{future, stream, StreamExt};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, CONTENT_TYPE},
+    StatusCode,
+};
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::{
     cmp::Ordering,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::{self, Display},
+    future::Future,
+    io::Write,
+    ops::ControlFlow,
+    sync::Arc,
+    time::Duration,
+};
+use time::{
+    format_description::well_known::Rfc3339, macros::format_description, serde::rfc3339, Date,
+    OffsetDateTime, UtcOffset, Weekday,
 };
-use time::{serde::rfc3339, Date, OffsetDateTime};
 use url::Url;
 
-const BASE_URL: &str = "https://www.tagesschau.de/api2u/news";
+const BASE_URL: &str = "https://www.tagesschau.de/";
+
+/// The default maximum size of a single API response, 16 MiB.
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 16 * 1024 * 1024;
+
+/// The maximum number of requests [`fetch_many`] runs at the same time.
+const FETCH_MANY_CONCURRENCY: usize = 4;
+
+/// The maximum number of redirects resolved at the same time, see [`TRequestBuilder::resolve_redirects`].
+const RESOLVE_URLS_CONCURRENCY: usize = 4;
+
+/// The language used for human readable names of [`Regions`](Region) and [`Months`](Month).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// German names, e.g. `Bayern` or `März`.
+    #[default]
+    German,
+    /// English names, e.g. `Bavaria` or `March`.
+    English,
+}
 
 /// The german federal states.
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Region {
     #[allow(missing_docs)]
     BadenWürttemberg = 1,
@@ -56,6 +89,66 @@ pub enum Region {
     Thüringen = 16,
 }
 
+impl Region {
+    /// All german federal states.
+    pub const ALL: [Region; 16] = [
+        Region::BadenWürttemberg,
+        Region::Bayern,
+        Region::Berlin,
+        Region::Brandenburg,
+        Region::Bremen,
+        Region::Hamburg,
+        Region::Hessen,
+        Region::MecklenburgVorpommern,
+        Region::Niedersachsen,
+        Region::NordrheinWestfalen,
+        Region::RheinlandPfalz,
+        Region::Saarland,
+        Region::Sachsen,
+        Region::SachsenAnhalt,
+        Region::SchleswigHolstein,
+        Region::Thüringen,
+    ];
+
+    /// Get the name of this `Region` in the given [`Locale`].
+    pub fn name(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (_, Region::BadenWürttemberg) => "Baden-Württemberg",
+            (Locale::German, Region::Bayern) => "Bayern",
+            (Locale::English, Region::Bayern) => "Bavaria",
+            (_, Region::Berlin) => "Berlin",
+            (_, Region::Brandenburg) => "Brandenburg",
+            (_, Region::Bremen) => "Bremen",
+            (_, Region::Hamburg) => "Hamburg",
+            (Locale::German, Region::Hessen) => "Hessen",
+            (Locale::English, Region::Hessen) => "Hesse",
+            (Locale::German, Region::MecklenburgVorpommern) => "Mecklenburg-Vorpommern",
+            (Locale::English, Region::MecklenburgVorpommern) => "Mecklenburg-Western Pomerania",
+            (Locale::German, Region::Niedersachsen) => "Niedersachsen",
+            (Locale::English, Region::Niedersachsen) => "Lower Saxony",
+            (Locale::German, Region::NordrheinWestfalen) => "Nordrhein-Westfalen",
+            (Locale::English, Region::NordrheinWestfalen) => "North Rhine-Westphalia",
+            (Locale::German, Region::RheinlandPfalz) => "Rheinland-Pfalz",
+            (Locale::English, Region::RheinlandPfalz) => "Rhineland-Palatinate",
+            (_, Region::Saarland) => "Saarland",
+            (Locale::German, Region::Sachsen) => "Sachsen",
+            (Locale::English, Region::Sachsen) => "Saxony",
+            (Locale::German, Region::SachsenAnhalt) => "Sachsen-Anhalt",
+            (Locale::English, Region::SachsenAnhalt) => "Saxony-Anhalt",
+            (_, Region::SchleswigHolstein) => "Schleswig-Holstein",
+            (Locale::German, Region::Thüringen) => "Thüringen",
+            (Locale::English, Region::Thüringen) => "Thuringia",
+        }
+    }
+}
+
+impl Display for Region {
+    /// Formats the region using its german name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name(Locale::German))
+    }
+}
+
 /// Months of the year.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -87,7 +180,33 @@ pub enum Month {
 }
 
 impl Month {
-    fn to_time_month(&self) -> time::Month {
+    /// Get the name of this `Month` in the given [`Locale`].
+    pub fn name(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::German, Month::January) => "Januar",
+            (Locale::English, Month::January) => "January",
+            (Locale::German, Month::February) => "Februar",
+            (Locale::English, Month::February) => "February",
+            (Locale::German, Month::March) => "März",
+            (Locale::English, Month::March) => "March",
+            (_, Month::April) => "April",
+            (Locale::German, Month::May) => "Mai",
+            (Locale::English, Month::May) => "May",
+            (Locale::German, Month::June) => "Juni",
+            (Locale::English, Month::June) => "June",
+            (Locale::German, Month::July) => "Juli",
+            (Locale::English, Month::July) => "July",
+            (_, Month::August) => "August",
+            (_, Month::September) => "September",
+            (Locale::German, Month::October) => "Oktober",
+            (Locale::English, Month::October) => "October",
+            (_, Month::November) => "November",
+            (Locale::German, Month::December) => "Dezember",
+            (Locale::English, Month::December) => "December",
+        }
+    }
+
+    fn to_time_month(self) -> time::Month {
         match self {
             Month::January => time::Month::January,
             Month::February => time::Month::February,
@@ -122,6 +241,13 @@ impl Month {
     }
 }
 
+impl Display for Month {
+    /// Formats the month using its german name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name(Locale::German))
+    }
+}
+
 /// The different available news categorys
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
 pub enum Ressort {
@@ -146,17 +272,43 @@ pub enum Ressort {
 impl Display for Ressort {
     /// Formats the ressort value in a way that is usable by the underlying API.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_api_str())
+    }
+}
+
+impl Ressort {
+    /// Get the value of this `Ressort` used by the underlying API, e.g. `"inland"`.
+    pub fn as_api_str(&self) -> &'static str {
         match self {
-            Ressort::None => f.write_str(""),
-            Ressort::Inland => f.write_str("inland"),
-            Ressort::Ausland => f.write_str("ausland"),
-            Ressort::Wirtschaft => f.write_str("wirtschaft"),
-            Ressort::Sport => f.write_str("sport"),
-            Ressort::Video => f.write_str("video"),
-            Ressort::Investigativ => f.write_str("investigativ"),
-            Ressort::Wissen => f.write_str("wissen"),
+            Ressort::None => "",
+            Ressort::Inland => "inland",
+            Ressort::Ausland => "ausland",
+            Ressort::Wirtschaft => "wirtschaft",
+            Ressort::Sport => "sport",
+            Ressort::Video => "video",
+            Ressort::Investigativ => "investigativ",
+            Ressort::Wissen => "wissen",
         }
     }
+
+    /// Checks if this `Ressort` can be combined with a region filter.
+    ///
+    /// Regional news only exist for [`Ressort::None`], [`Ressort::Inland`], [`Ressort::Wirtschaft`],
+    /// [`Ressort::Investigativ`] and [`Ressort::Wissen`]. [`Ressort::Ausland`], [`Ressort::Sport`] and
+    /// [`Ressort::Video`] do not support regions.
+    pub fn supports_regions(&self) -> bool {
+        !matches!(self, Ressort::Ausland | Ressort::Sport | Ressort::Video)
+    }
+}
+
+impl Serialize for Ressort {
+    /// Serializes the ressort as the value used by the underlying API.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_api_str())
+    }
 }
 
 impl<'de> Deserialize<'de> for Ressort {
@@ -182,7 +334,39 @@ impl<'de> Deserialize<'de> for Ressort {
     }
 }
 
+/// The type of a [Content], as reported by the API in its `type` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentKind {
+    /// A regular article, `"story"`.
+    Story,
+    /// A live blog, `"liveblog"`.
+    Liveblog,
+    /// A photo gallery, `"gallery"`.
+    Gallery,
+    /// An embedded web page, `"webview"`.
+    Webview,
+    /// A video, `"video"`.
+    Video,
+    /// A type not known to this crate.
+    Other,
+}
+
+impl ContentKind {
+    fn from_api_str(kind: &str) -> Self {
+        match kind {
+            "story" => ContentKind::Story,
+            "liveblog" => ContentKind::Liveblog,
+            "gallery" => ContentKind::Gallery,
+            "webview" => ContentKind::Webview,
+            "video" => ContentKind::Video,
+            _ => ContentKind::Other,
+        }
+    }
+}
+
 /// A timeframe for which the news should be fetched.
+#[derive(Clone)]
 pub enum Timeframe {
     /// The current date.
     Now,
@@ -190,6 +374,13 @@ pub enum Timeframe {
     Date(TDate),
     /// A range of dates.
     DateRange(DateRange),
+    /// Every day of a calendar month.
+    Month {
+        /// The year of the month.
+        year: i32,
+        /// The month of the year.
+        month: Month,
+    },
 }
 
 /// A date format for usage in [`Timeframes`](Timeframe).
@@ -207,6 +398,48 @@ impl TDate {
         Ok(TDate::from_time_date(date))
     }
 
+    /// Checks if this `TDate` is after the current local date.
+    pub fn is_future(&self) -> Result<bool, Error> {
+        let today = TDate::from_time_date(OffsetDateTime::now_local()?.date());
+
+        Ok(*self > today)
+    }
+
+    /// Parses a `TDate` from either the ISO format `YYYY-MM-DD` or the german format `DD.MM.YYYY`.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidDateFormat(s.to_string());
+
+        let parts: Vec<&str> = if s.contains('-') {
+            s.split('-').collect()
+        } else {
+            s.split('.').rev().collect()
+        };
+
+        let [year, month, day] = parts[..] else {
+            return Err(invalid());
+        };
+
+        if year.len() != 4 {
+            return Err(invalid());
+        }
+
+        let year: i32 = year.parse().map_err(|_| invalid())?;
+        let month: u8 = month.parse().map_err(|_| invalid())?;
+        let day: u8 = day.parse().map_err(|_| invalid())?;
+
+        let month = time::Month::try_from(month)?;
+
+        TDate::from_calendar_date(year, Month::from_time_month(month), day)
+    }
+
+    fn to_time_date(self) -> Result<Date, Error> {
+        Ok(Date::from_calendar_date(
+            self.year,
+            self.month.to_time_month(),
+            self.day,
+        )?)
+    }
+
     /// Creates a `TDate` from a [Date].
     pub fn from_time_date(d: Date) -> Self {
         TDate {
@@ -217,14 +450,40 @@ impl TDate {
     }
 }
 
+impl From<Date> for TDate {
+    fn from(d: Date) -> Self {
+        TDate::from_time_date(d)
+    }
+}
+
+impl From<OffsetDateTime> for TDate {
+    /// Creates a `TDate` from the date component of an [OffsetDateTime].
+    fn from(dt: OffsetDateTime) -> Self {
+        TDate::from_time_date(dt.date())
+    }
+}
+
+impl PartialOrd for TDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TDate {
+    /// Orders `TDates` chronologically.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month as u8, self.day).cmp(&(other.year, other.month as u8, other.day))
+    }
+}
+
 impl Display for TDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}{}{}",
+            "{}{:0>2}{:0>2}",
             self.year % 100,
-            format!("{:0>2}", self.month as u8),
-            format!("{:0>2}", self.day)
+            self.month as u8,
+            self.day
         )
     }
 }
@@ -250,23 +509,147 @@ impl DateRange {
         }
 
         Ok(Self {
-            dates: HashSet::from_iter(dates.into_iter()),
+            dates: HashSet::from_iter(dates),
         })
     }
 
+    /// Generates a `DateRange` containing the seven days, Monday to Sunday, of the given ISO calendar week.
+    ///
+    /// Fails with [`Error::DateParsingError`] if the year has no such week.
+    pub fn from_iso_week(year: i32, week: u8) -> Result<Self, Error> {
+        let monday = Date::from_iso_week_date(year, week, Weekday::Monday)?;
+        let sunday = Date::from_iso_week_date(year, week, Weekday::Sunday)?;
+
+        Self::new(TDate::from_time_date(monday), TDate::from_time_date(sunday))
+    }
+
     /// Creates a `DateRange` from a collection of [`TDates`](TDate).
     pub fn from_dates(dates: Vec<TDate>) -> Self {
         Self {
-            dates: HashSet::from_iter(dates.into_iter()),
+            dates: HashSet::from_iter(dates),
+        }
+    }
+
+    /// Removes the given [`TDates`](TDate) from an existing `DateRange`.
+    pub fn without(&mut self, dates: &[TDate]) -> &mut DateRange {
+        for date in dates {
+            self.dates.remove(date);
+        }
+        self
+    }
+}
+
+/// The version of the news endpoint to query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiVersion {
+    /// The `/api2/news` endpoint.
+    V2,
+    /// The `/api2u/news` endpoint.
+    #[default]
+    V2u,
+}
+
+impl ApiVersion {
+    /// The path of the news endpoint relative to the base URL.
+    fn path(self) -> &'static str {
+        match self {
+            ApiVersion::V2 => "api2/news",
+            ApiVersion::V2u => "api2u/news",
         }
     }
 }
 
+/// How failing requests are handled when querying multiple dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorMode {
+    /// Abort on the first failing request and return its error.
+    #[default]
+    FailFast,
+    /// Query all dates and return [`Error::Multiple`] with every error and the successfully fetched articles
+    /// if any request failed.
+    Collect,
+}
+
+/// How text articles and videos are arranged in the results of a `TRequestBuilder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupMode {
+    /// Text articles and videos are mixed and sorted by date.
+    #[default]
+    Interleaved,
+    /// All text articles sorted by date, followed by all videos sorted by date.
+    TextThenVideo,
+    /// All videos sorted by date, followed by all text articles sorted by date.
+    VideoThenText,
+}
+
+/// Which fields of the articles are deserialized by [`TRequestBuilder::get_projected`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Projection {
+    /// Complete articles as [Content].
+    #[default]
+    Full,
+    /// Only the title, date, URL and ressort as [Headline].
+    Minimal,
+    /// Only the title, date, image and streams as [Media].
+    MediaOnly,
+}
+
+/// The articles returned by [`TRequestBuilder::get_projected`], depending on the selected [`Projection`].
+#[derive(Debug)]
+pub enum Projected {
+    /// The articles of [`Projection::Full`].
+    Full(Vec<Content>),
+    /// The articles of [`Projection::Minimal`].
+    Minimal(Vec<Headline>),
+    /// The articles of [`Projection::MediaOnly`].
+    MediaOnly(Vec<Media>),
+}
+
 /// A client for the [Tagesschau](https://www.tagesschau.de) `/api2/news` endpoint.
+#[derive(Clone)]
 pub struct TRequestBuilder {
     ressort: Ressort,
-    regions: HashSet<Region>,
+    regions: BTreeSet<Region>,
     timeframe: Timeframe,
+    accept_language: Option<String>,
+    max_response_bytes: usize,
+    transport: Option<Arc<dyn Transport>>,
+    /// A client with the default settings shared by the requests of [fetch_many].
+    shared_client: Option<reqwest::Client>,
+    error_mode: ErrorMode,
+    api_version: ApiVersion,
+    include_kinds: Option<HashSet<ContentKind>>,
+    exclude_kinds: HashSet<ContentKind>,
+    breaking_only: bool,
+    assume_utc_on_indeterminate: bool,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    plain_text: bool,
+    strict_deserialization: bool,
+    resolve_redirects: bool,
+    spillover_hours: u8,
+    deadline: Option<Duration>,
+    #[cfg(feature = "cookies")]
+    cookie_store: bool,
+    on_progress: Option<Arc<ProgressFn>>,
+    max_redirects: Option<usize>,
+    dedup_titles: bool,
+    endpoint: Option<String>,
+    reject_future_dates: bool,
+    group_mode: GroupMode,
+    display_offset: Option<UtcOffset>,
+    timeframe_set: bool,
+    projection: Projection,
+    headers: HeaderMap,
+}
+
+/// A callback receiving the number of queried dates, the total number of dates and the last queried date.
+type ProgressFn = dyn Fn(usize, usize, TDate) + Send + Sync;
+
+impl Default for TRequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TRequestBuilder {
@@ -274,8 +657,37 @@ impl TRequestBuilder {
     pub fn new() -> Self {
         Self {
             ressort: Ressort::None,
-            regions: HashSet::new(),
+            regions: BTreeSet::new(),
             timeframe: Timeframe::Now,
+            accept_language: None,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            transport: None,
+            shared_client: None,
+            error_mode: ErrorMode::FailFast,
+            api_version: ApiVersion::V2u,
+            include_kinds: None,
+            exclude_kinds: HashSet::new(),
+            breaking_only: false,
+            assume_utc_on_indeterminate: false,
+            timeout: None,
+            connect_timeout: None,
+            plain_text: false,
+            strict_deserialization: true,
+            resolve_redirects: false,
+            spillover_hours: 0,
+            deadline: None,
+            #[cfg(feature = "cookies")]
+            cookie_store: true,
+            on_progress: None,
+            max_redirects: None,
+            dedup_titles: false,
+            endpoint: None,
+            reject_future_dates: false,
+            group_mode: GroupMode::Interleaved,
+            display_offset: None,
+            timeframe_set: false,
+            projection: Projection::Full,
+            headers: HeaderMap::new(),
         }
     }
 
@@ -286,21 +698,300 @@ impl TRequestBuilder {
     }
 
     /// Sets an existing `TRequestBuilder`'s selected regions.
+    ///
+    /// The regions are kept in a sorted set, so the generated request URLs are reproducible. The API returns the
+    /// regional articles separately, they are merged into the results and skipped if they are already part of the
+    /// national news.
     pub fn regions(&mut self, reg: HashSet<Region>) -> &mut TRequestBuilder {
-        self.regions = reg;
+        self.regions = BTreeSet::from_iter(reg);
         self
     }
 
     /// Sets an existing `TRequestBuilder`'s selected timeframe.
+    ///
+    /// Only one timeframe can be selected, so calling this again replaces the previously selected timeframe,
+    /// including dates removed with [`exclude_dates`](TRequestBuilder::exclude_dates). Use
+    /// [`timeframe_is_set`](TRequestBuilder::timeframe_is_set) to detect an earlier call.
     pub fn timeframe(&mut self, timeframe: Timeframe) -> &mut TRequestBuilder {
         self.timeframe = timeframe;
+        self.timeframe_set = true;
+        self
+    }
+
+    /// Checks if the timeframe of an existing `TRequestBuilder` was set explicitly, instead of using the default
+    /// [`Timeframe::Now`].
+    pub fn timeframe_is_set(&self) -> bool {
+        self.timeframe_set
+    }
+
+    /// Removes the given [`TDates`](TDate) from an existing `TRequestBuilder`'s selected timeframe.
+    ///
+    /// This only has an effect if the timeframe is a [`Timeframe::DateRange`].
+    pub fn exclude_dates(&mut self, dates: &[TDate]) -> &mut TRequestBuilder {
+        if let Timeframe::DateRange(date_range) = &mut self.timeframe {
+            date_range.without(dates);
+        }
+        self
+    }
+
+    /// Sets the `Accept-Language` header sent with every request of an existing `TRequestBuilder`.
+    pub fn accept_language(&mut self, lang: &str) -> &mut TRequestBuilder {
+        self.accept_language = Some(lang.to_string());
+        self
+    }
+
+    /// Adds a header sent with every request of an existing `TRequestBuilder`, e.g. `Authorization` for an
+    /// authenticating gateway.
+    ///
+    /// Setting the same header again replaces its value. This only applies without a custom [Transport]. Fails with
+    /// [`Error::InvalidHeader`] if the name or value is not a valid HTTP header.
+    pub fn header(&mut self, name: &str, value: &str) -> Result<&mut TRequestBuilder, Error> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| Error::InvalidHeader(name.to_string()))?;
+        let value =
+            HeaderValue::from_str(value).map_err(|_| Error::InvalidHeader(name.to_string()))?;
+
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Sets the maximum size in bytes of a single response an existing `TRequestBuilder` will accept.
+    ///
+    /// Responses exceeding this limit fail with [`Error::ResponseTooLarge`]. Defaults to 16 MiB.
+    pub fn max_response_bytes(&mut self, limit: usize) -> &mut TRequestBuilder {
+        self.max_response_bytes = limit;
+        self
+    }
+
+    /// Sets the timeout of each request of an existing `TRequestBuilder`, from connecting until the response
+    /// body has been read.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut TRequestBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for only the connect phase of each request of an existing `TRequestBuilder`.
+    ///
+    /// Exceeding it fails with [`Error::ConnectTimeout`].
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut TRequestBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the [`Transport`] an existing `TRequestBuilder` uses for its async requests.
+    ///
+    /// By default a [reqwest] based transport honoring the other settings of the `TRequestBuilder` is used.
+    pub fn transport(&mut self, transport: impl Transport + 'static) -> &mut TRequestBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets how an existing `TRequestBuilder` handles failing requests when querying multiple dates.
+    pub fn error_mode(&mut self, mode: ErrorMode) -> &mut TRequestBuilder {
+        self.error_mode = mode;
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` only returns breaking news.
+    ///
+    /// The API offers no parameter to request only breaking news, so the full results are fetched and
+    /// filtered afterwards. This does not reduce the amount of data transferred.
+    pub fn breaking_only(&mut self, breaking_only: bool) -> &mut TRequestBuilder {
+        self.breaking_only = breaking_only;
         self
     }
 
+    /// Sets if an existing `TRequestBuilder` uses the current UTC date when the local offset can't be determined,
+    /// instead of failing with [`Error::DateError`].
+    ///
+    /// This commonly happens in containers and CI environments. A warning is logged whenever the fallback is used.
+    pub fn assume_utc_on_indeterminate(&mut self, assume_utc: bool) -> &mut TRequestBuilder {
+        self.assume_utc_on_indeterminate = assume_utc;
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` strips HTML tags and decodes HTML entities in the title,
+    /// topline and first sentence of the returned articles.
+    ///
+    /// When disabled, the values are returned exactly as sent by the API.
+    pub fn plain_text(&mut self, plain_text: bool) -> &mut TRequestBuilder {
+        self.plain_text = plain_text;
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` silently ignores fields of the API response this crate doesn't model.
+    ///
+    /// When disabled, every unknown field of the response, its articles and their images is logged as a
+    /// [tracing] warning, which helps noticing changes of the API. Defaults to `true`.
+    pub fn strict_deserialization(&mut self, strict: bool) -> &mut TRequestBuilder {
+        self.strict_deserialization = strict;
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` replaces the URL of every [`TextArticle`] with the URL it redirects to,
+    /// see [`TextArticle::resolve_url`].
+    ///
+    /// This issues one additional request per article, at most four at the same time, with the same headers as the
+    /// queries. It only applies to the async methods and is skipped if a custom [Transport] is set, since a
+    /// transport can't follow redirects.
+    pub fn resolve_redirects(&mut self, resolve: bool) -> &mut TRequestBuilder {
+        self.resolve_redirects = resolve;
+        self
+    }
+
+    /// Sets the number of hours after midnight in which an existing `TRequestBuilder` with [`Timeframe::Now`] also
+    /// queries the previous day.
+    ///
+    /// Shortly after midnight the current date has hardly any articles, so the results of the previous day are
+    /// merged in. Defaults to `0`, which disables this.
+    pub fn spillover_hours(&mut self, hours: u8) -> &mut TRequestBuilder {
+        self.spillover_hours = hours;
+        self
+    }
+
+    /// Sets the maximum total duration of a single query of an existing `TRequestBuilder`, across all of its dates.
+    ///
+    /// Once it is exceeded, the remaining dates are skipped and [`Error::DeadlineExceeded`] is returned, or added to
+    /// [`Error::Multiple`] with [`ErrorMode::Collect`]. Blocking requests only check the deadline between dates.
+    pub fn deadline(&mut self, deadline: Duration) -> &mut TRequestBuilder {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` keeps cookies between the requests of a single query.
+    ///
+    /// This only applies to the async methods without a custom [Transport]. Defaults to `true`.
+    #[cfg(feature = "cookies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cookies")))]
+    pub fn cookie_store(&mut self, enabled: bool) -> &mut TRequestBuilder {
+        self.cookie_store = enabled;
+        self
+    }
+
+    /// Sets a callback an existing `TRequestBuilder` invokes after each queried date with the number of finished
+    /// dates, the total number of dates and the date that just finished, e.g. to drive a progress bar.
+    ///
+    /// The callback is called for failed dates as well.
+    pub fn on_progress(
+        &mut self,
+        f: impl Fn(usize, usize, TDate) + Send + Sync + 'static,
+    ) -> &mut TRequestBuilder {
+        self.on_progress = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets the maximum number of redirects an existing `TRequestBuilder` follows.
+    ///
+    /// Exceeding it fails with [`Error::TooManyRedirects`], so `0` treats every redirect as an error. Defaults to
+    /// reqwest's limit of 10 redirects. This only applies without a custom [Transport].
+    pub fn redirect_policy(&mut self, max: usize) -> &mut TRequestBuilder {
+        self.max_redirects = Some(max);
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` keeps only the newest article of all articles with exactly the same title.
+    ///
+    /// This is lossy, as different articles may share a title, but removes e.g. repeated live blog updates from a
+    /// list of headlines. Defaults to `false`.
+    pub fn dedup_titles(&mut self, dedup: bool) -> &mut TRequestBuilder {
+        self.dedup_titles = dedup;
+        self
+    }
+
+    /// Sets if an existing `TRequestBuilder` fails with [`Error::DateInFuture`] when the selected timeframe contains a
+    /// date after the current local date, for which the API returns no articles.
+    ///
+    /// Defaults to `false`, as the local date may lag behind the date in Germany shortly after midnight.
+    pub fn reject_future_dates(&mut self, reject: bool) -> &mut TRequestBuilder {
+        self.reject_future_dates = reject;
+        self
+    }
+
+    /// Sets the [`GroupMode`] an existing `TRequestBuilder` arranges text articles and videos with.
+    ///
+    /// This only applies to [`get_all_articles`](TRequestBuilder::get_all_articles) and its blocking counterpart, all
+    /// other methods keep their results sorted by date.
+    pub fn group_mode(&mut self, mode: GroupMode) -> &mut TRequestBuilder {
+        self.group_mode = mode;
+        self
+    }
+
+    /// Sets the [`UtcOffset`] an existing `TRequestBuilder` converts the publishing times of all articles to.
+    ///
+    /// The API sends the times in German time. The raw date strings are kept unchanged.
+    pub fn display_offset(&mut self, offset: UtcOffset) -> &mut TRequestBuilder {
+        self.display_offset = Some(offset);
+        self
+    }
+
+    /// Sets the [`Projection`] an existing `TRequestBuilder` uses for [`get_projected`](TRequestBuilder::get_projected).
+    pub fn projection(&mut self, projection: Projection) -> &mut TRequestBuilder {
+        self.projection = projection;
+        self
+    }
+
+    /// Sets the [`ApiVersion`] an existing `TRequestBuilder` queries.
+    pub fn api_version(&mut self, version: ApiVersion) -> &mut TRequestBuilder {
+        self.api_version = version;
+        self
+    }
+
+    /// Sets the path of the news endpoint an existing `TRequestBuilder` queries, overriding the [`ApiVersion`].
+    ///
+    /// The path is resolved relative to `https://www.tagesschau.de/`, so both `api2u/news` and `api2u/news/` are
+    /// valid, the latter avoiding a redirect on setups that require the trailing slash. A full URL replaces the host
+    /// as well, e.g. to query a mirror.
+    pub fn endpoint(&mut self, path: &str) -> &mut TRequestBuilder {
+        self.endpoint = Some(path.to_string());
+        self
+    }
+
+    /// Restricts the results of an existing `TRequestBuilder` to the given [`ContentKinds`](ContentKind).
+    pub fn include_kinds(&mut self, kinds: &[ContentKind]) -> &mut TRequestBuilder {
+        self.include_kinds = Some(HashSet::from_iter(kinds.iter().copied()));
+        self
+    }
+
+    /// Removes the given [`ContentKinds`](ContentKind) from the results of an existing `TRequestBuilder`.
+    pub fn exclude_kinds(&mut self, kinds: &[ContentKind]) -> &mut TRequestBuilder {
+        self.exclude_kinds = HashSet::from_iter(kinds.iter().copied());
+        self
+    }
+
+    /// Checks that the parameters currently specified on the `TRequestBuilder` Object can be combined.
+    ///
+    /// Fails with [`Error::IncompatibleFilters`] if regions are set together with a [`Ressort`] that doesn't
+    /// [support regions](Ressort::supports_regions), and with [`Error::DateInFuture`] if future dates are
+    /// [rejected](TRequestBuilder::reject_future_dates) and the timeframe contains one.
+    pub fn validate(&self) -> Result<(), Error> {
+        if !self.regions.is_empty() && !self.ressort.supports_regions() {
+            return Err(Error::IncompatibleFilters(format!(
+                "ressort {:?} does not support region filters",
+                self.ressort
+            )));
+        }
+
+        if self.reject_future_dates {
+            let today = self.today()?;
+
+            if let Some(date) = self.dates()?.into_iter().find(|date| *date > today) {
+                return Err(Error::DateInFuture(date));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Creates the queryable URL for the `fetch` method.
+    ///
+    /// The parameters are not [validated](TRequestBuilder::validate) here, queries do that once before fetching.
     fn prepare_url(&self, date: TDate) -> Result<String, Error> {
         // TODO - Support multiple ressorts
-        let mut url = Url::parse(BASE_URL)?;
+        let path = match &self.endpoint {
+            Some(endpoint) => endpoint.as_str(),
+            None => self.api_version.path(),
+        };
+        let mut url = Url::parse(BASE_URL)?.join(path)?;
 
         url.query_pairs_mut().append_pair("date", &date.to_string());
 
@@ -315,126 +1006,1894 @@ impl TRequestBuilder {
 
         if self.ressort != Ressort::None {
             url.query_pairs_mut()
-                .append_pair("ressort", &self.ressort.to_string());
+                .append_pair("ressort", self.ressort.as_api_str());
         }
 
         Ok(url.to_string())
     }
 
-    /// Processes the URLs created by `prepare_url`.
-    async fn fetch(&self, date: TDate) -> Result<Articles, Error> {
-        let url = self.prepare_url(date)?;
-
-        let response = reqwest::get(url).await.map_err(|e| Error::BadRequest(e))?;
-
-        let text = match response.status() {
-            StatusCode::OK => response.text().await.map_err(|e| Error::ParsingError(e))?,
-            _ => Err(Error::InvalidResponse(response.status().as_u16()))?,
-        };
-
-        let articles: Articles = serde_json::from_str(&text)?;
-
-        Ok(articles)
-    }
-
-    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content].
-    pub async fn get_all_articles(&self) -> Result<Vec<Content>, Error> {
+    /// Resolves the selected timeframe into the individual dates to query.
+    fn dates(&self) -> Result<Vec<TDate>, Error> {
         let dates: Vec<TDate> = match &self.timeframe {
             Timeframe::Now => {
-                let now = OffsetDateTime::now_local()?;
+                let now = self.now()?;
+                let today = TDate::from_time_date(now.date());
 
-                vec![TDate::from_time_date(now.date())]
+                match now.date().previous_day() {
+                    Some(yesterday) if now.hour() < self.spillover_hours => {
+                        vec![TDate::from_time_date(yesterday), today]
+                    }
+                    _ => vec![today],
+                }
             }
             Timeframe::Date(date) => {
                 vec![*date]
             }
-            Timeframe::DateRange(date_range) => {
-                Vec::from_iter(date_range.dates.clone().into_iter())
+            Timeframe::DateRange(date_range) => Vec::from_iter(date_range.dates.clone()),
+            Timeframe::Month { year, month } => {
+                let mut dates: Vec<TDate> = Vec::new();
+                let mut day = Date::from_calendar_date(*year, month.to_time_month(), 1)?;
+
+                while day.month() == month.to_time_month() {
+                    dates.push(TDate::from_time_date(day));
+
+                    match day.next_day() {
+                        Some(next) => day = next,
+                        None => break,
+                    }
+                }
+
+                dates
             }
         };
 
-        let mut content: Vec<Content> = Vec::new();
+        Ok(dates)
+    }
 
-        for date in dates {
-            let mut art = self.fetch(date).await?;
+    /// Get the current local date, falling back to UTC if enabled.
+    fn today(&self) -> Result<TDate, Error> {
+        Ok(TDate::from_time_date(self.now()?.date()))
+    }
+
+    /// Get the current local time, falling back to UTC if enabled.
+    fn now(&self) -> Result<OffsetDateTime, Error> {
+        match OffsetDateTime::now_local() {
+            Ok(now) => Ok(now),
+            Err(_) if self.assume_utc_on_indeterminate => {
+                tracing::warn!(
+                    "Unable to determine the local offset, using the current UTC time instead"
+                );
 
-            content.append(&mut art.news)
+                Ok(OffsetDateTime::now_utc())
+            }
+            Err(e) => Err(e.into()),
         }
+    }
 
-        content.sort_by(|element, next| {
-            let date_element = match element {
-                Content::TextArticle(t) => t.date,
-                Content::Video(v) => v.date,
-            };
+    /// Checks if the selected timeframe includes the current local date, meaning its results may still change.
+    pub fn is_live(&self) -> Result<bool, Error> {
+        let today = self.today()?;
 
-            let date_next = match next {
-                Content::TextArticle(t) => t.date,
-                Content::Video(v) => v.date,
-            };
+        Ok(self.dates()?.contains(&today))
+    }
 
-            if date_element > date_next {
-                Ordering::Greater
-            } else if date_element < date_next {
-                Ordering::Less
-            } else {
-                Ordering::Equal
-            }
-        });
+    /// Describes the selected ressort and regions for error messages.
+    fn filters(&self) -> String {
+        let regions: Vec<String> = self.regions.iter().map(|r| r.to_string()).collect();
 
-        Ok(content)
+        format!(
+            "ressort: {:?}, regions: [{}]",
+            self.ressort,
+            regions.join(", ")
+        )
     }
 
-    /// Query only [`TextArticle`] articles that match the parameters currently specified on the `TRequestBuilder` Object.
-    pub async fn get_text_articles(&self) -> Result<Vec<TextArticle>, Error> {
-        let articles = self.get_all_articles().await;
+    /// Processes the URLs created by `prepare_url`.
+    async fn fetch(&self, date: TDate) -> Result<Articles, Error> {
+        let text = self.fetch_text(date).await?;
 
-        match articles {
-            Ok(mut a) => {
-                a.retain(|x| x.is_text());
-                let mut t: Vec<TextArticle> = Vec::new();
+        self.parse_articles(&text)
+    }
 
-                for content in a {
-                    t.push(content.to_text().unwrap())
-                }
+    /// Fetches the articles of the given date, deserializing each article into `T`, see [parse_merged].
+    async fn fetch_as<T: DeserializeOwned>(&self, date: TDate) -> Result<Vec<T>, Error> {
+        let text = self.fetch_text(date).await?;
 
-                Ok(t)
-            }
-            Err(e) => Err(e),
+        parse_merged(&text)
+    }
+
+    /// Parses a response body into [`Articles`], logging unknown fields if deserialization isn't strict.
+    fn parse_articles(&self, text: &str) -> Result<Articles, Error> {
+        if !self.strict_deserialization {
+            log_unknown_fields(text);
         }
+
+        let mut articles: Articles = parse_response(text)?;
+
+        articles.assign_order();
+
+        Ok(articles)
     }
 
-    /// Query only [`Videos`](Video) that match the parameters currently specified on the `TRequestBuilder` Object.
-    pub async fn get_video_articles(&self) -> Result<Vec<Video>, Error> {
-        let articles = self.get_all_articles().await;
+    /// Fetches the given URL instead of one created by `prepare_url` and parses the response into [Content].
+    ///
+    /// This is an escape hatch for queries the `TRequestBuilder` doesn't model, e.g. experimental endpoints. The
+    /// response is handled like any other, so the filters of the `TRequestBuilder` still apply and the articles are
+    /// sorted by date.
+    pub async fn fetch_url(&self, url: Url) -> Result<Vec<Content>, Error> {
+        let text = self.get_text(url.as_str()).await?;
+        let articles = self.parse_articles(&text)?;
 
-        match articles {
-            Ok(mut a) => {
-                a.retain(|x| x.is_video());
-                let mut t: Vec<Video> = Vec::new();
+        self.finish(articles.into_content(), Vec::new())
+    }
 
-                for content in a {
-                    t.push(content.to_video().unwrap())
-                }
+    /// Fetches the unparsed response body for a single date.
+    async fn fetch_text(&self, date: TDate) -> Result<String, Error> {
+        let url = self.prepare_url(date)?;
 
-                Ok(t)
-            }
-            Err(e) => Err(e),
-        }
+        self.get_text(&url).await
     }
-}
 
-#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
-#[cfg(feature = "blocking")]
-mod blocking;
+    /// Fetches the unparsed response body of the given URL.
+    async fn get_text(&self, url: &str) -> Result<String, Error> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
-#[derive(Deserialize, Debug)]
-struct Articles {
-    news: Vec<Content>,
-}
+        let result = match &self.transport {
+            Some(transport) => transport.get(url).await,
+            None => self.default_transport()?.get(url).await,
+        };
 
-/// A value returned by the [TRequestBuilder] that can be either a text article or a video.
-#[derive(Deserialize, Debug)]
-#[serde(untagged)]
+        #[cfg(feature = "metrics")]
+        self.record_metrics(&result, start.elapsed());
+
+        result
+    }
+
+    /// Query the unmodified response body for the given date with the parameters currently specified on the
+    /// `TRequestBuilder` Object, e.g. to hash or archive it.
+    ///
+    /// The body is neither decoded nor parsed, so it matches the bytes sent by the API exactly.
+    pub async fn fetch_bytes(&self, date: TDate) -> Result<Vec<u8>, Error> {
+        self.validate()?;
+
+        let url = self.prepare_url(date)?;
+
+        match &self.transport {
+            Some(transport) => transport.get_bytes(&url).await,
+            None => self.default_transport()?.get_bytes(&url).await,
+        }
+    }
+
+    /// Records the request count, duration and received bytes of a single request.
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self, result: &Result<String, Error>, elapsed: Duration) {
+        let status = match result {
+            Ok(_) => "200".to_string(),
+            Err(Error::InvalidResponse(code)) => code.to_string(),
+            Err(_) => "error".to_string(),
+        };
+        let ressort = self.ressort.to_string();
+
+        metrics::counter!("tagesschau_requests_total", "status" => status.clone(), "ressort" => ressort.clone())
+            .increment(1);
+        metrics::histogram!("tagesschau_request_duration_seconds", "status" => status, "ressort" => ressort)
+            .record(elapsed.as_secs_f64());
+
+        if let Ok(text) = result {
+            metrics::counter!("tagesschau_bytes_received").increment(text.len() as u64);
+        }
+    }
+
+    /// Creates the reqwest based [`Transport`] used when no custom transport is set.
+    fn default_transport(&self) -> Result<ReqwestTransport, Error> {
+        Ok(ReqwestTransport {
+            client: self.client()?,
+            accept_language: self.accept_language.clone(),
+            headers: self.headers.clone(),
+            max_response_bytes: self.max_response_bytes,
+        })
+    }
+
+    /// Creates a [`reqwest::Client`] honoring the timeouts of the `TRequestBuilder`.
+    fn client(&self) -> Result<reqwest::Client, Error> {
+        if let Some(client) = &self.shared_client {
+            return Ok(client.clone());
+        }
+
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(max) = self.max_redirects {
+            builder = builder.redirect(reqwest::redirect::Policy::limited(max));
+        }
+
+        #[cfg(feature = "cookies")]
+        {
+            builder = builder.cookie_store(self.cookie_store);
+        }
+
+        builder.build().map_err(Error::ClientError)
+    }
+
+    /// Checks if the requests of the `TRequestBuilder` can use a client with the default settings.
+    fn has_default_client(&self) -> bool {
+        #[cfg(feature = "cookies")]
+        if !self.cookie_store {
+            return false;
+        }
+
+        self.transport.is_none()
+            && self.timeout.is_none()
+            && self.connect_timeout.is_none()
+            && self.max_redirects.is_none()
+    }
+
+    /// Creates a copy of the `TRequestBuilder` whose requests share a single client, so connections and cookies are
+    /// reused between them.
+    fn session(&self) -> Result<TRequestBuilder, Error> {
+        let mut session = self.clone();
+
+        if session.transport.is_none() {
+            session.transport = Some(Arc::new(self.default_transport()?));
+        }
+
+        Ok(session)
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content].
+    ///
+    /// The articles are sorted by date and arranged according to the selected [`GroupMode`].
+    pub async fn get_all_articles(&self) -> Result<Vec<Content>, Error> {
+        self.group(self.all_articles().await)
+    }
+
+    /// Queries all articles sorted by date, regardless of the selected [`GroupMode`].
+    async fn all_articles(&self) -> Result<Vec<Content>, Error> {
+        let dates = self.dates()?;
+
+        self.fetch_dates(dates).await
+    }
+
+    /// Arranges date-sorted articles according to the selected [`GroupMode`], including those of
+    /// [`Error::Multiple`].
+    fn group(&self, result: Result<Vec<Content>, Error>) -> Result<Vec<Content>, Error> {
+        let arrange = |content: &mut Vec<Content>| match self.group_mode {
+            GroupMode::Interleaved => {}
+            GroupMode::TextThenVideo => content.sort_by_key(Content::is_video),
+            GroupMode::VideoThenText => content.sort_by_key(Content::is_text),
+        };
+
+        match result {
+            Ok(mut content) => {
+                arrange(&mut content);
+                Ok(content)
+            }
+            Err(Error::Multiple {
+                errors,
+                mut content,
+            }) => {
+                arrange(&mut content);
+                Err(Error::Multiple { errors, content })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Queries the given dates with up to `concurrency` requests at the same time and passes each successful result
+    /// to `handle` in the order of the dates, until it breaks.
+    ///
+    /// This [validates](TRequestBuilder::validate) the parameters once, honors the deadline, error mode and progress
+    /// callback of the `TRequestBuilder` and returns the errors collected with [`ErrorMode::Collect`].
+    async fn for_each_date<T, F, Fut>(
+        &self,
+        dates: Vec<TDate>,
+        concurrency: usize,
+        fetch: F,
+        mut handle: impl FnMut(TDate, T) -> ControlFlow<()>,
+    ) -> Result<Vec<Error>, Error>
+    where
+        F: Fn(TDate) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        self.validate()?;
+
+        let mut errors: Vec<Error> = Vec::new();
+
+        let deadline = self
+            .deadline
+            .map(|deadline| tokio::time::Instant::now() + deadline);
+        let total = dates.len();
+        let fetch = &fetch;
+
+        let mut results = stream::iter(dates)
+            .map(|date| async move {
+                let result = match deadline {
+                    Some(deadline) => tokio::time::timeout_at(deadline, fetch(date))
+                        .await
+                        .unwrap_or(Err(Error::DeadlineExceeded)),
+                    None => fetch(date).await,
+                };
+
+                (date, result)
+            })
+            .buffered(concurrency.max(1))
+            .enumerate();
+
+        while let Some((done, (date, result))) = results.next().await {
+            if let Some(on_progress) = &self.on_progress {
+                on_progress(done + 1, total, date);
+            }
+
+            match result {
+                Ok(value) => {
+                    if handle(date, value).is_break() {
+                        break;
+                    }
+                }
+                Err(Error::DeadlineExceeded) if self.error_mode == ErrorMode::Collect => {
+                    errors.push(Error::DeadlineExceeded);
+                    break;
+                }
+                Err(e) if self.error_mode == ErrorMode::Collect => errors.push(e),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Queries the given dates and merges the results sorted by date.
+    async fn fetch_dates(&self, dates: Vec<TDate>) -> Result<Vec<Content>, Error> {
+        let (content, _) = self.fetch_dates_concurrently(dates, 1).await?;
+
+        Ok(content)
+    }
+
+    /// Queries the given dates with up to `concurrency` requests at the same time and merges the results sorted by
+    /// date, together with the dates the API returned no articles for.
+    async fn fetch_dates_concurrently(
+        &self,
+        dates: Vec<TDate>,
+        concurrency: usize,
+    ) -> Result<(Vec<Content>, Vec<TDate>), Error> {
+        let mut content: Vec<Content> = Vec::new();
+        let mut empty: Vec<TDate> = Vec::new();
+
+        let session = self.session()?;
+
+        let errors = self
+            .for_each_date(
+                dates,
+                concurrency,
+                |date| session.fetch(date),
+                |date, art| {
+                    if art.news.is_empty() && art.regional.is_empty() {
+                        empty.push(date);
+                    }
+
+                    content.append(&mut art.into_content());
+
+                    ControlFlow::Continue(())
+                },
+            )
+            .await?;
+
+        let mut content = self.finish(content, errors)?;
+
+        if self.resolve_redirects {
+            self.resolve_urls(&mut content).await?;
+        }
+
+        empty.sort();
+
+        Ok((content, empty))
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of
+    /// [Content], together with the sorted dates for which the API returned no articles at all.
+    ///
+    /// Dates are reported before any filters of the `TRequestBuilder` are applied, so a date whose articles were all
+    /// filtered out is not considered empty.
+    pub async fn get_with_coverage(&self) -> Result<(Vec<Content>, Vec<TDate>), Error> {
+        let dates = self.dates()?;
+
+        self.fetch_dates_concurrently(dates, 1).await
+    }
+
+    /// Replaces the URL of every [`TextArticle`] with the URL it redirects to.
+    ///
+    /// URLs that can't be resolved are kept as they are.
+    async fn resolve_urls(&self, content: &mut [Content]) -> Result<(), Error> {
+        if self.transport.is_some() {
+            return Ok(());
+        }
+
+        let client = self.client()?;
+
+        let articles: Vec<&mut TextArticle> = content
+            .iter_mut()
+            .filter_map(|content| match content {
+                Content::TextArticle(t) => Some(t),
+                Content::Video(_) => None,
+            })
+            .collect();
+
+        // Futures are lazy, so only the buffered ones send requests.
+        let requests: Vec<_> = articles
+            .into_iter()
+            .map(|article| self.resolve_article(&client, article))
+            .collect();
+
+        stream::iter(requests)
+            .buffer_unordered(RESOLVE_URLS_CONCURRENCY)
+            .collect::<Vec<()>>()
+            .await;
+
+        Ok(())
+    }
+
+    /// Replaces the URL of the given [`TextArticle`] with the URL it redirects to, keeping it if it can't be resolved.
+    async fn resolve_article(&self, client: &reqwest::Client, article: &mut TextArticle) {
+        let resolved = match article.canonical_url() {
+            Ok(url) => {
+                let mut request = client.head(url).headers(self.headers.clone());
+
+                if let Some(lang) = &self.accept_language {
+                    request = request.header(ACCEPT_LANGUAGE, lang);
+                }
+
+                resolve_redirect(request).await
+            }
+            Err(e) => Err(e),
+        };
+
+        match resolved {
+            Ok(url) => article.url = url.to_string(),
+            Err(e) => tracing::warn!("Unable to resolve `{}`: {}", article.url, e),
+        }
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in chunks of
+    /// `chunk_days` consecutive dates, passing each chunk's articles to `f` before fetching the next one.
+    ///
+    /// This keeps memory usage bounded for large timeframes. Processing stops at the first error returned by `f`.
+    /// The deadline and the progress callback cover all chunks together. With [`ErrorMode::Collect`] the articles of
+    /// the successful dates of a chunk are still passed to `f` and the errors of all chunks are returned as
+    /// [`Error::Multiple`] at the end, without any articles.
+    pub async fn get_all_chunked(
+        &self,
+        chunk_days: usize,
+        mut f: impl FnMut(Vec<Content>) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let mut dates = self.dates()?;
+        dates.sort();
+
+        let total = dates.len();
+        let deadline = self
+            .deadline
+            .map(|deadline| tokio::time::Instant::now() + deadline);
+
+        let mut builder = self.clone();
+        let mut done = 0;
+        let mut errors: Vec<Error> = Vec::new();
+
+        for chunk in dates.chunks(chunk_days.max(1)) {
+            if let Some(deadline) = deadline {
+                builder.deadline =
+                    Some(deadline.saturating_duration_since(tokio::time::Instant::now()));
+            }
+
+            if let Some(on_progress) = self.on_progress.clone() {
+                let offset = done;
+
+                builder.on_progress = Some(Arc::new(move |chunk_done, _, date| {
+                    on_progress(offset + chunk_done, total, date)
+                }));
+            }
+
+            let content = match builder.fetch_dates(chunk.to_vec()).await {
+                Ok(content) => content,
+                Err(Error::Multiple {
+                    errors: mut failed,
+                    content,
+                }) => {
+                    errors.append(&mut failed);
+                    content
+                }
+                Err(e) => return Err(e),
+            };
+            done += chunk.len();
+
+            f(content)?;
+
+            if matches!(errors.last(), Some(Error::DeadlineExceeded)) {
+                break;
+            }
+        }
+
+        collected((), errors)
+    }
+
+    /// Filters the collected articles, sorts them by date and reports the errors collected along the way, if any.
+    fn finish(&self, mut content: Vec<Content>, errors: Vec<Error>) -> Result<Vec<Content>, Error> {
+        if let Some(kinds) = &self.include_kinds {
+            content.retain(|content| kinds.contains(&content.content_kind()));
+        }
+
+        content.retain(|content| !self.exclude_kinds.contains(&content.content_kind()));
+
+        if self.breaking_only {
+            content.retain(|content| content.is_breaking());
+        }
+
+        if self.plain_text {
+            content.iter_mut().for_each(Content::strip_html);
+        }
+
+        if let Some(offset) = self.display_offset {
+            content
+                .iter_mut()
+                .for_each(|content| content.convert_offset(offset));
+        }
+
+        content.sort_by_key(|content| content.date());
+
+        if self.dedup_titles {
+            let mut seen: HashSet<String> = HashSet::new();
+
+            content.reverse();
+            content.retain(|content| seen.insert(content.as_view().title.to_string()));
+            content.reverse();
+        }
+
+        if errors.is_empty() {
+            Ok(content)
+        } else {
+            Err(Error::Multiple { errors, content })
+        }
+    }
+
+    /// Repeatedly query the current articles that match the parameters currently specified on the `TRequestBuilder`
+    /// Object, waiting `interval` between queries, and pass the articles that were not part of the previous query to
+    /// `on_new`.
+    ///
+    /// The selected timeframe is replaced with [`Timeframe::Now`] and the first query passes all articles. Articles
+    /// are compared by their [Sophora ID](Content::sophora_id), falling back to their URL. Every poll is a full query,
+    /// as the API gives no way to request only changes.
+    ///
+    /// Failing queries after the first one are logged as a [tracing] warning and retried after `interval`, so this
+    /// only returns if the first query fails. Drop the returned future to stop polling.
+    pub async fn poll(
+        &self,
+        interval: Duration,
+        mut on_new: impl FnMut(&[Content]),
+    ) -> Result<(), Error> {
+        let mut live = self.clone();
+        live.timeframe(Timeframe::Now);
+
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut first = true;
+
+        loop {
+            match live.get_all_articles().await {
+                Ok(content) => {
+                    let current: HashSet<String> = content.iter().map(Content::identity).collect();
+                    let new: Vec<Content> = content
+                        .into_iter()
+                        .filter(|content| !seen.contains(&content.identity()))
+                        .collect();
+
+                    if !new.is_empty() {
+                        on_new(&new);
+                    }
+
+                    seen = current;
+                }
+                Err(e) if first => return Err(e),
+                Err(e) => tracing::warn!("Polling articles failed: {}", e),
+            }
+
+            first = false;
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Query the [`Headlines`](Headline) of all articles that match the parameters currently specified on the
+    /// `TRequestBuilder` Object, sorted by date.
+    ///
+    /// Only the title, date, URL and ressort are deserialized, which is considerably faster than parsing full
+    /// articles over large timeframes.
+    pub async fn get_headlines(&self) -> Result<Vec<Headline>, Error> {
+        let mut headlines: Vec<Headline> = Vec::new();
+        let session = self.session()?;
+
+        let errors = self
+            .for_each_date(
+                self.dates()?,
+                1,
+                |date| session.fetch_as::<Headline>(date),
+                |_, mut art| {
+                    headlines.append(&mut art);
+
+                    ControlFlow::Continue(())
+                },
+            )
+            .await?;
+
+        headlines.sort_by_key(|headline| headline.date);
+
+        collected(headlines, errors)
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object, deserializing
+    /// only the fields of the selected [`Projection`], sorted by date.
+    ///
+    /// Skipping the remaining fields makes parsing considerably faster over large timeframes. The filters of the
+    /// `TRequestBuilder` only apply to [`Projection::Full`].
+    pub async fn get_projected(&self) -> Result<Projected, Error> {
+        match self.projection {
+            Projection::Full => Ok(Projected::Full(self.all_articles().await?)),
+            Projection::Minimal => Ok(Projected::Minimal(self.get_headlines().await?)),
+            Projection::MediaOnly => {
+                let mut media: Vec<Media> = self.get_all_as().await?;
+                media.sort_by_key(|media| media.date);
+
+                Ok(Projected::MediaOnly(media))
+            }
+        }
+    }
+
+    /// Query the articles of each of the given [`Ressorts`](Ressort) separately and concurrently, merged and sorted
+    /// by date.
+    ///
+    /// Each article is tagged with the ressort it was fetched under, since the API doesn't always include it.
+    /// The ressort currently specified on the `TRequestBuilder` Object is ignored, all other parameters apply.
+    pub async fn get_all_for_ressorts(
+        &self,
+        ressorts: &[Ressort],
+    ) -> Result<Vec<TaggedContent>, Error> {
+        let requests = ressorts.iter().map(|ressort| {
+            let mut builder = self.clone();
+            builder.ressort(*ressort);
+
+            async move {
+                let content = builder.all_articles().await?;
+
+                Ok::<_, Error>(content.into_iter().map(move |content| TaggedContent {
+                    requested_ressort: *ressort,
+                    content,
+                }))
+            }
+        });
+
+        let mut tagged: Vec<TaggedContent> = future::try_join_all(requests)
+            .await?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        tagged.sort_by_key(|tagged| tagged.content.date());
+
+        Ok(tagged)
+    }
+
+    /// Count the articles per date that match the parameters currently specified on the `TRequestBuilder` Object,
+    /// without deserializing the articles themselves.
+    ///
+    /// Only the fields needed for filtering are parsed, so the counts match the results of
+    /// [`get_all_articles`](TRequestBuilder::get_all_articles), including its filters and deduplication.
+    pub async fn get_counts_per_day(&self) -> Result<BTreeMap<TDate, usize>, Error> {
+        let mut kept: BTreeMap<TDate, Vec<CountedArticle>> = BTreeMap::new();
+        let session = self.session()?;
+
+        let errors = self
+            .for_each_date(
+                self.dates()?,
+                1,
+                |date| session.fetch_as::<CountedArticle>(date),
+                |date, art| {
+                    kept.insert(date, self.counted(art));
+
+                    ControlFlow::Continue(())
+                },
+            )
+            .await?;
+
+        let mut counts: BTreeMap<TDate, usize> = BTreeMap::new();
+        let mut titles: HashSet<String> = HashSet::new();
+
+        // Like `finish`, only the latest article with a title is kept.
+        for (date, articles) in kept.into_iter().rev() {
+            let count = if self.dedup_titles {
+                articles
+                    .into_iter()
+                    .filter(|article| titles.insert(article.title.clone()))
+                    .count()
+            } else {
+                articles.len()
+            };
+
+            counts.insert(date, count);
+        }
+
+        collected(counts, errors)
+    }
+
+    /// Filters the articles of a single response the same way `finish` does.
+    fn counted(&self, mut counted: Vec<CountedArticle>) -> Vec<CountedArticle> {
+        counted.retain(|article| {
+            let kind = ContentKind::from_api_str(&article.kind);
+
+            self.include_kinds
+                .as_ref()
+                .is_none_or(|kinds| kinds.contains(&kind))
+                && !self.exclude_kinds.contains(&kind)
+                && (!self.breaking_only || article.breaking_news == Some(true))
+        });
+
+        counted
+    }
+
+    /// Query the teaser images of all articles that match the parameters currently specified on the `TRequestBuilder` Object,
+    /// together with the title of the article they belong to.
+    ///
+    /// Images without variants are skipped and images sharing a variant URL are only returned once.
+    pub async fn get_images(&self) -> Result<Vec<ArticleImage>, Error> {
+        let content = self.all_articles().await?;
+
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut images: Vec<ArticleImage> = Vec::new();
+
+        for item in content {
+            let view = item.as_view();
+
+            let Some(image) = view.image else {
+                continue;
+            };
+
+            let Some(variants) = image.image_variants.as_ref().filter(|v| !v.is_empty()) else {
+                continue;
+            };
+
+            let mut is_new = true;
+            for url in variants.values() {
+                if !seen.insert(url.clone()) {
+                    is_new = false;
+                }
+            }
+
+            if is_new {
+                images.push(ArticleImage {
+                    article_title: view.title.to_string(),
+                    image: image.clone(),
+                });
+            }
+        }
+
+        Ok(images)
+    }
+
+    /// Query the articles of every [`Region`] separately and concurrently, returning them per region.
+    ///
+    /// The regions currently specified on the `TRequestBuilder` Object are ignored, all other parameters apply. At
+    /// most four regions are queried at the same time. Each region only contains the articles that
+    /// [belong to it](Content::regions), every article only once, so national news is left out.
+    ///
+    /// With [`ErrorMode::Collect`] the remaining regions are still queried and [`Error::Multiple`] contains the
+    /// articles of all regions together.
+    pub async fn get_all_regional(&self) -> Result<HashMap<Region, Vec<Content>>, Error> {
+        let mut requests = stream::iter(Region::ALL)
+            .map(|region| {
+                let mut builder = self.clone();
+                builder.regions(HashSet::from([region]));
+
+                async move { (region, builder.all_articles().await) }
+            })
+            .buffered(FETCH_MANY_CONCURRENCY);
+
+        let mut regional: HashMap<Region, Vec<Content>> = HashMap::new();
+        let mut errors: Vec<Error> = Vec::new();
+
+        while let Some((region, result)) = requests.next().await {
+            let mut content = match result {
+                Ok(content) => content,
+                Err(Error::Multiple {
+                    errors: mut failed,
+                    content,
+                }) => {
+                    errors.append(&mut failed);
+                    content
+                }
+                Err(e) if self.error_mode == ErrorMode::Collect => {
+                    errors.push(e);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let mut seen: HashSet<String> = HashSet::new();
+            content.retain(|content| {
+                content.regions().contains(&region) && seen.insert(content.identity())
+            });
+
+            regional.insert(region, content);
+        }
+
+        if errors.is_empty() {
+            Ok(regional)
+        } else {
+            let mut content: Vec<Content> = regional.into_values().flatten().collect();
+            content.sort_by_key(|content| content.date());
+
+            Err(Error::Multiple { errors, content })
+        }
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object,
+    /// deserializing each article into a custom type `T`.
+    ///
+    /// `T` must match the JSON shape of a single article as returned by the API; fields not present on `T` are ignored.
+    /// Since the crate does not know where `T` keeps its date, the articles are not sorted. Regional articles that are
+    /// also part of the national news are only returned once, like for [Content].
+    pub async fn get_all_as<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error> {
+        let mut items: Vec<T> = Vec::new();
+        let session = self.session()?;
+
+        let errors = self
+            .for_each_date(
+                self.dates()?,
+                1,
+                |date| session.fetch_as::<T>(date),
+                |_, mut art| {
+                    items.append(&mut art);
+
+                    ControlFlow::Continue(())
+                },
+            )
+            .await?;
+
+        collected(items, errors)
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content],
+    /// returning [`Error::NoResults`] instead of an empty result.
+    pub async fn get_all_articles_nonempty(&self) -> Result<Vec<Content>, Error> {
+        let content = self.all_articles().await?;
+
+        if content.is_empty() {
+            return Err(Error::NoResults {
+                dates: self.dates()?,
+                filters: self.filters(),
+            });
+        }
+
+        Ok(content)
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content]
+    /// and were published within the given duration before now.
+    pub async fn get_recent(&self, within: Duration) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles().await?;
+
+        retain_recent(&mut content, within);
+
+        Ok(content)
+    }
+
+    /// Query all articles for the day after the single [`Timeframe::Date`] currently specified on the
+    /// `TRequestBuilder` Object, with all other parameters unchanged.
+    ///
+    /// Fails with [`Error::InvalidTimeframe`] if the timeframe isn't a single date.
+    pub async fn for_next_day(&self) -> Result<Vec<Content>, Error> {
+        self.for_adjacent_day(Date::next_day)?
+            .get_all_articles()
+            .await
+    }
+
+    /// Query all articles for the day before the single [`Timeframe::Date`] currently specified on the
+    /// `TRequestBuilder` Object, with all other parameters unchanged.
+    ///
+    /// Fails with [`Error::InvalidTimeframe`] if the timeframe isn't a single date.
+    pub async fn for_previous_day(&self) -> Result<Vec<Content>, Error> {
+        self.for_adjacent_day(Date::previous_day)?
+            .get_all_articles()
+            .await
+    }
+
+    /// Creates a copy of this `TRequestBuilder` for the date next to its single date timeframe.
+    fn for_adjacent_day(&self, step: fn(Date) -> Option<Date>) -> Result<TRequestBuilder, Error> {
+        let Timeframe::Date(date) = &self.timeframe else {
+            return Err(Error::InvalidTimeframe(
+                "navigating to an adjacent day requires a single date".to_string(),
+            ));
+        };
+
+        let adjacent = step(date.to_time_date()?).ok_or_else(|| {
+            Error::InvalidTimeframe("the adjacent day is out of range".to_string())
+        })?;
+
+        let mut builder = self.clone();
+        builder.timeframe(Timeframe::Date(TDate::from_time_date(adjacent)));
+
+        Ok(builder)
+    }
+
+    /// Query the most recent article that matches the parameters currently specified on the `TRequestBuilder` Object.
+    pub async fn get_latest(&self) -> Result<Option<Content>, Error> {
+        let mut content = self.all_articles().await?;
+
+        Ok(content.pop())
+    }
+
+    /// Query the `n` most recent articles that match the parameters currently specified on the `TRequestBuilder` Object,
+    /// ordered from newest to oldest.
+    pub async fn get_latest_n(&self, n: usize) -> Result<Vec<Content>, Error> {
+        let content = self.all_articles().await?;
+
+        Ok(content.into_iter().rev().take(n).collect())
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content],
+    /// with breaking news first and the remaining articles ordered from newest to oldest.
+    pub async fn get_all_articles_prioritized(&self) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles().await?;
+
+        prioritize(&mut content);
+
+        Ok(content)
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object in form of [Content]
+    /// and contain `query` in their title, topline or first sentence, ignoring case.
+    pub async fn search(&self, query: &str) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles().await?;
+
+        retain_matching(&mut content, query);
+
+        Ok(content)
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object and whose
+    /// [Sophora ID](Content::sophora_id) starts with `prefix`.
+    ///
+    /// Articles without an ID are removed.
+    pub async fn get_by_id_prefix(&self, prefix: &str) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles().await?;
+
+        content.retain(|content| {
+            content
+                .sophora_id()
+                .is_some_and(|id| id.starts_with(prefix))
+        });
+
+        Ok(content)
+    }
+
+    /// Query the articles of the current date that match the parameters currently specified on the `TRequestBuilder`
+    /// Object and report the fields, ressorts and types of the response that are not modeled by this crate.
+    ///
+    /// The response is not deserialized into [Content], so this also works when the API changed in an incompatible
+    /// way, which makes it suitable for a scheduled check.
+    pub async fn validate_schema(&self) -> Result<SchemaReport, Error> {
+        self.validate()?;
+
+        let text = self.fetch_text(self.today()?).await?;
+        let value: serde_json::Value = parse_response(&text)?;
+
+        Ok(UnknownFields::collect(&value).into())
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object, split into
+    /// breaking news and all other articles.
+    ///
+    /// Both lists are sorted by date.
+    pub async fn get_partitioned(&self) -> Result<(Vec<Content>, Vec<Content>), Error> {
+        let content = self.all_articles().await?;
+
+        Ok(content.into_iter().partition(Content::is_breaking))
+    }
+
+    /// Query the newest breaking news article that matches the parameters currently specified on the
+    /// `TRequestBuilder` Object.
+    ///
+    /// The dates are queried one by one, newest first, and querying stops as soon as a date contains breaking news.
+    /// Returns `None` if no date contains breaking news.
+    pub async fn get_first_breaking(&self) -> Result<Option<Content>, Error> {
+        let session = self.session()?;
+        let mut dates = self.dates()?;
+        dates.sort_by(|a, b| b.cmp(a));
+
+        let mut breaking: Option<Content> = None;
+
+        let errors = self
+            .for_each_date(
+                dates,
+                1,
+                |date| {
+                    let session = &session;
+
+                    async move {
+                        let art = session.fetch(date).await?;
+                        let content = self.finish(art.into_content(), Vec::new())?;
+
+                        Ok(content.into_iter().rev().find(Content::is_breaking))
+                    }
+                },
+                |_, found| {
+                    breaking = found;
+
+                    match breaking {
+                        Some(_) => ControlFlow::Break(()),
+                        None => ControlFlow::Continue(()),
+                    }
+                },
+            )
+            .await?;
+
+        if errors.is_empty() {
+            Ok(breaking)
+        } else {
+            Err(Error::Multiple {
+                errors,
+                content: breaking.into_iter().collect(),
+            })
+        }
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object, split into
+    /// [`TextArticles`](TextArticle) and [`Videos`](Video).
+    ///
+    /// Unlike calling [`get_text_articles`](TRequestBuilder::get_text_articles) and
+    /// [`get_video_articles`](TRequestBuilder::get_video_articles), the articles are only queried once. Both lists
+    /// keep the order of [`get_all_articles`](TRequestBuilder::get_all_articles).
+    pub async fn get_split(&self) -> Result<(Vec<TextArticle>, Vec<Video>), Error> {
+        let articles = self.all_articles().await?;
+
+        Ok(split(articles))
+    }
+
+    /// Query all articles that match the parameters currently specified on the `TRequestBuilder` Object and belong
+    /// to the given [`Region`].
+    ///
+    /// Unlike [`regions`](TRequestBuilder::regions), this filters by the [regions of each article](Content::regions)
+    /// after querying, so a single broad query can be split by region. Articles without a region are removed.
+    pub async fn get_for_region(&self, region: Region) -> Result<Vec<Content>, Error> {
+        let mut content = self.all_articles().await?;
+
+        content.retain(|content| content.regions().contains(&region));
+
+        Ok(content)
+    }
+
+    /// Query only [`TextArticle`] articles that match the parameters currently specified on the `TRequestBuilder` Object.
+    pub async fn get_text_articles(&self) -> Result<Vec<TextArticle>, Error> {
+        let articles = self.all_articles().await?;
+
+        Ok(articles
+            .into_iter()
+            .filter_map(|content| content.to_text().ok())
+            .collect())
+    }
+
+    /// Query only [`Videos`](Video) that match the parameters currently specified on the `TRequestBuilder` Object.
+    pub async fn get_video_articles(&self) -> Result<Vec<Video>, Error> {
+        let articles = self.all_articles().await?;
+
+        Ok(articles
+            .into_iter()
+            .filter_map(|content| content.to_video().ok())
+            .collect())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+#[cfg(feature = "blocking")]
+mod blocking;
+
+/// The HTTP transport used by the [TRequestBuilder] to query the API.
+///
+/// Implementing this trait allows replacing the network layer, e.g. with a fake returning canned JSON in tests.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Fetches the given URL and returns the response body.
+    async fn get(&self, url: &str) -> Result<String, Error>;
+
+    /// Fetches the given URL and returns the unmodified response body.
+    ///
+    /// Defaults to the bytes of the body returned by [`get`](Transport::get).
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, Error> {
+        self.get(url).await.map(String::into_bytes)
+    }
+}
+
+/// A source of news, implemented by [TRequestBuilder].
+///
+/// Depending on this trait instead of the concrete builder allows storing a `Box<dyn NewsSource>` and swapping
+/// in a different implementation, e.g. a fake in tests.
+#[async_trait]
+pub trait NewsSource: Send + Sync {
+    /// Query all articles of this source in form of [Content], sorted by date.
+    async fn get_all(&self) -> Result<Vec<Content>, Error>;
+}
+
+#[async_trait]
+impl NewsSource for TRequestBuilder {
+    async fn get_all(&self) -> Result<Vec<Content>, Error> {
+        self.all_articles().await
+    }
+}
+
+/// The default [`Transport`], backed by [reqwest].
+struct ReqwestTransport {
+    client: reqwest::Client,
+    accept_language: Option<String>,
+    headers: HeaderMap,
+    max_response_bytes: usize,
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn get(&self, url: &str) -> Result<String, Error> {
+        let body = self.get_bytes(url).await?;
+
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, Error> {
+        let mut request = self.client.get(url).headers(self.headers.clone());
+
+        if let Some(lang) = &self.accept_language {
+            request = request.header(ACCEPT_LANGUAGE, lang);
+        }
+
+        let response = request.send().await.map_err(request_error)?;
+
+        match response.status() {
+            StatusCode::OK => {
+                check_content_type(response.headers())?;
+
+                read_body(response, self.max_response_bytes).await
+            }
+            _ => Err(Error::InvalidResponse(response.status().as_u16())),
+        }
+    }
+}
+
+/// Fails with [`Error::UnexpectedContentType`] if the response is an HTML page instead of JSON.
+fn check_content_type(headers: &HeaderMap) -> Result<(), Error> {
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+
+    match content_type {
+        Some(content_type) if content_type.starts_with("text/html") => {
+            Err(Error::UnexpectedContentType {
+                content_type: Some(content_type.to_string()),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Sends the given request and returns the URL of the response after following all redirects.
+async fn resolve_redirect(request: reqwest::RequestBuilder) -> Result<Url, Error> {
+    let response = request.send().await.map_err(request_error)?;
+
+    if !response.status().is_success() {
+        return Err(Error::InvalidResponse(response.status().as_u16()));
+    }
+
+    Ok(response.url().clone())
+}
+
+/// Deserializes a response body, attaching the surrounding JSON to any error.
+///
+/// Bodies starting with `<` are HTML error pages and fail with [`Error::UnexpectedContentType`].
+fn parse_response<T: DeserializeOwned>(text: &str) -> Result<T, Error> {
+    if text.trim_start().starts_with('<') {
+        return Err(Error::UnexpectedContentType { content_type: None });
+    }
+
+    serde_json::from_str(text).map_err(|source| {
+        let snippet = error_snippet(text, &source);
+
+        Error::DeserializationFailed { source, snippet }
+    })
+}
+
+/// Deserializes the articles of a response body into `T`, merging the regional articles into the national ones
+/// like [`Articles::into_content`].
+///
+/// `T` can't be identified, so the body is parsed a second time for the [ArticleKey] of each article.
+fn parse_merged<T: DeserializeOwned>(text: &str) -> Result<Vec<T>, Error> {
+    let articles: Articles<T> = parse_response(text)?;
+    let keys: Articles<ArticleKey> = parse_response(text)?;
+
+    let national: HashSet<String> = keys.news.iter().map(ArticleKey::identity).collect();
+
+    let mut items = articles.news;
+    items.extend(
+        articles
+            .regional
+            .into_iter()
+            .zip(keys.regional)
+            .filter(|(_, key)| !national.contains(&key.identity()))
+            .map(|(item, _)| item),
+    );
+
+    Ok(items)
+}
+
+/// Extracts the JSON surrounding the position of a deserialization error.
+fn error_snippet(text: &str, error: &serde_json::Error) -> String {
+    const CONTEXT: usize = 40;
+
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(error.line().saturating_sub(1))
+        .map(|line| line.len())
+        .sum();
+    let position = (line_start + error.column().saturating_sub(1)).min(text.len());
+
+    let mut start = position.saturating_sub(CONTEXT);
+    while !text.is_char_boundary(start) {
+        start -= 1;
+    }
+
+    let mut end = (position + CONTEXT).min(text.len());
+    while !text.is_char_boundary(end) {
+        end += 1;
+    }
+
+    text[start..end].to_string()
+}
+
+/// Maps a failed request, distinguishing connect timeouts from other failures.
+fn request_error(e: reqwest::Error) -> Error {
+    if e.is_connect() && e.is_timeout() {
+        Error::ConnectTimeout(e)
+    } else if e.is_redirect() {
+        Error::TooManyRedirects(e)
+    } else {
+        Error::BadRequest(e)
+    }
+}
+
+/// The fields of a response this crate deserializes.
+const RESPONSE_FIELDS: &[&str] = &["news", "regional"];
+
+/// The fields of an article this crate deserializes, either as [`TextArticle`] or [`Video`].
+const ARTICLE_FIELDS: &[&str] = &[
+    "sophoraId",
+    "regionId",
+    "regionIds",
+    "externalId",
+    "comments",
+    "title",
+    "topline",
+    "firstSentence",
+    "date",
+    "detailsweb",
+    "shareURL",
+    "streams",
+    "tags",
+    "ressort",
+    "type",
+    "breakingNews",
+    "teaserImage",
+];
+
+/// The fields of an [`Image`] this crate deserializes.
+const IMAGE_FIELDS: &[&str] = &["title", "copyright", "alttext", "imageVariants", "type"];
+
+/// The fields of a response that are not modeled by this crate.
+#[derive(Debug, Default)]
+struct UnknownFields {
+    response: BTreeSet<String>,
+    article: BTreeSet<String>,
+    image: BTreeSet<String>,
+    ressorts: BTreeSet<String>,
+    kinds: BTreeSet<String>,
+}
+
+impl UnknownFields {
+    /// Collects the unknown fields of the response, its articles and their images, as well as unknown ressort and
+    /// type values.
+    fn collect(value: &serde_json::Value) -> Self {
+        let mut unknown = UnknownFields::default();
+
+        let Some(response) = value.as_object() else {
+            return unknown;
+        };
+
+        unknown.response = unknown_keys(response, RESPONSE_FIELDS);
+
+        let articles = RESPONSE_FIELDS
+            .iter()
+            .filter_map(|field| response.get(*field)?.as_array())
+            .flatten()
+            .filter_map(|article| article.as_object());
+
+        for article in articles {
+            unknown
+                .article
+                .append(&mut unknown_keys(article, ARTICLE_FIELDS));
+
+            if let Some(ressort) = article.get("ressort").and_then(|r| r.as_str()) {
+                if serde_json::from_value::<Ressort>(ressort.into()).is_err() {
+                    unknown.ressorts.insert(ressort.to_string());
+                }
+            }
+
+            if let Some(kind) = article.get("type").and_then(|k| k.as_str()) {
+                if ContentKind::from_api_str(kind) == ContentKind::Other {
+                    unknown.kinds.insert(kind.to_string());
+                }
+            }
+
+            if let Some(image) = article.get("teaserImage").and_then(|i| i.as_object()) {
+                unknown.image.append(&mut unknown_keys(image, IMAGE_FIELDS));
+            }
+        }
+
+        unknown
+    }
+}
+
+fn unknown_keys(
+    object: &serde_json::Map<String, serde_json::Value>,
+    known: &[&str],
+) -> BTreeSet<String> {
+    object
+        .keys()
+        .filter(|key| !known.contains(&key.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Deserializes a string, treating values of any other type as missing.
+fn lenient_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) if !s.is_empty() => Ok(Some(s)),
+        _ => Ok(None),
+    }
+}
+
+/// Deserializes a map of stream URLs, skipping entries that aren't strings and treating anything else as missing.
+fn lenient_streams<'de, D>(deserializer: D) -> Result<Option<HashMap<String, String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Object(map) => {
+            let streams: HashMap<String, String> = map
+                .into_iter()
+                .filter_map(|(key, value)| match value {
+                    serde_json::Value::String(url) => Some((key, url)),
+                    _ => None,
+                })
+                .collect();
+
+            Ok((!streams.is_empty()).then_some(streams))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Deserializes a single region ID or a list of them, treating values of any other type as missing.
+fn lenient_region_ids<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let id = |value: &serde_json::Value| value.as_u64().and_then(|id| u8::try_from(id).ok());
+
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Array(ids) => Ok(ids.iter().filter_map(id).collect()),
+        value => Ok(id(&value).into_iter().collect()),
+    }
+}
+
+/// Maps the region IDs of the API to [`Regions`](Region), skipping unknown IDs such as `0` for national news.
+fn regions_from_ids<'a>(ids: impl Iterator<Item = &'a u8>) -> BTreeSet<Region> {
+    ids.filter_map(|id| Region::ALL.into_iter().find(|region| *region as u8 == *id))
+        .collect()
+}
+
+/// Logs the fields of a response body that are not modeled by this crate.
+fn log_unknown_fields(text: &str) {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+        return;
+    };
+
+    let unknown = UnknownFields::collect(&value);
+
+    for field in unknown.response {
+        tracing::warn!("Unknown field `{}` in API response", field);
+    }
+
+    for field in unknown.article {
+        tracing::warn!("Unknown field `{}` in API article", field);
+    }
+
+    for field in unknown.image {
+        tracing::warn!("Unknown field `{}` in API image", field);
+    }
+
+    for ressort in unknown.ressorts {
+        tracing::warn!("Unknown ressort `{}` in API article", ressort);
+    }
+
+    for kind in unknown.kinds {
+        tracing::warn!("Unknown type `{}` in API article", kind);
+    }
+}
+
+/// A report of the parts of an API response that are not modeled by this crate, see
+/// [`TRequestBuilder::validate_schema`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaReport {
+    response_fields: BTreeSet<String>,
+    article_fields: BTreeSet<String>,
+    image_fields: BTreeSet<String>,
+    ressorts: BTreeSet<String>,
+    kinds: BTreeSet<String>,
+}
+
+impl SchemaReport {
+    /// Get the unknown top-level fields of the response of this `SchemaReport`.
+    pub fn response_fields(&self) -> &BTreeSet<String> {
+        &self.response_fields
+    }
+
+    /// Get the unknown fields of the articles of this `SchemaReport`.
+    pub fn article_fields(&self) -> &BTreeSet<String> {
+        &self.article_fields
+    }
+
+    /// Get the unknown fields of the images of this `SchemaReport`.
+    pub fn image_fields(&self) -> &BTreeSet<String> {
+        &self.image_fields
+    }
+
+    /// Get the `ressort` values that are not a known [`Ressort`] of this `SchemaReport`.
+    pub fn ressorts(&self) -> &BTreeSet<String> {
+        &self.ressorts
+    }
+
+    /// Get the `type` values that are not a known [`ContentKind`] of this `SchemaReport`.
+    pub fn kinds(&self) -> &BTreeSet<String> {
+        &self.kinds
+    }
+
+    /// Checks if this `SchemaReport` found no differences to the modeled schema.
+    pub fn is_empty(&self) -> bool {
+        self.response_fields.is_empty()
+            && self.article_fields.is_empty()
+            && self.image_fields.is_empty()
+            && self.ressorts.is_empty()
+            && self.kinds.is_empty()
+    }
+}
+
+impl From<UnknownFields> for SchemaReport {
+    fn from(unknown: UnknownFields) -> Self {
+        SchemaReport {
+            response_fields: unknown.response,
+            article_fields: unknown.article,
+            image_fields: unknown.image,
+            ressorts: unknown.ressorts,
+            kinds: unknown.kinds,
+        }
+    }
+}
+
+/// Reads the response body, aborting as soon as it exceeds `limit` bytes.
+async fn read_body(mut response: reqwest::Response, limit: usize) -> Result<Vec<u8>, Error> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > limit as u64)
+    {
+        return Err(Error::ResponseTooLarge(limit));
+    }
+
+    let mut body: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = response.chunk().await.map_err(Error::ParsingError)? {
+        if body.len() + chunk.len() > limit {
+            return Err(Error::ResponseTooLarge(limit));
+        }
+
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body)
+}
+
+/// Runs the given [`TRequestBuilders`](TRequestBuilder) concurrently, returning their results in the same order.
+///
+/// Requests without a custom [Transport] and with the default client settings (timeouts, redirect policy and cookie
+/// store) share a single client, so connections are reused across all of them. All other requests use their own
+/// client. At most four requests run at the same time.
+pub async fn fetch_many(requests: Vec<TRequestBuilder>) -> Vec<Result<Vec<Content>, Error>> {
+    let shared = TRequestBuilder::new().client().ok();
+
+    stream::iter(requests)
+        .map(|mut request| {
+            if request.has_default_client() {
+                request.shared_client = shared.clone();
+            }
+
+            async move { request.get_all_articles().await }
+        })
+        .buffered(FETCH_MANY_CONCURRENCY)
+        .collect()
+        .await
+}
+
+/// Merges the results of several queries into a single list, e.g. of [`TRequestBuilders`](TRequestBuilder) with
+/// different timeframes.
+///
+/// Articles are identified by their [Sophora ID](Content::sophora_id), falling back to their URL, and only the
+/// first occurrence is kept. Like [`get_all_articles`](TRequestBuilder::get_all_articles), the result is sorted by
+/// date, oldest first, keeping the original order of articles with the same date.
+pub fn merge_results(results: Vec<Vec<Content>>) -> Vec<Content> {
+    let mut seen: HashSet<String> = HashSet::new();
+
+    let mut content: Vec<Content> = results
+        .into_iter()
+        .flatten()
+        .filter(|content| seen.insert(content.identity()))
+        .collect();
+
+    content.sort_by_key(|content| content.date());
+
+    content
+}
+
+/// Groups the given [Content] into clusters of related articles, connecting two articles if they share at least
+/// `min_shared` tags.
+///
+/// Articles are also grouped transitively, so a cluster may contain articles without common tags if they are
+/// connected through others. Articles without related articles are left out. The clusters are ordered by their
+/// first article and keep the order of the given items.
+pub fn group_by_shared_tags(items: &[Content], min_shared: usize) -> Vec<Vec<&Content>> {
+    let tags: Vec<HashSet<&str>> = items
+        .iter()
+        .map(|content| {
+            content
+                .as_view()
+                .tags
+                .map(HashSet::from_iter)
+                .unwrap_or_default()
+        })
+        .collect();
+
+    // Every article points to another article of its cluster, the root of a cluster points to itself.
+    let mut parents: Vec<usize> = (0..items.len()).collect();
+
+    fn root(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    for i in 0..items.len() {
+        for j in i + 1..items.len() {
+            if tags[i].intersection(&tags[j]).count() >= min_shared.max(1) {
+                let (a, b) = (root(&mut parents, i), root(&mut parents, j));
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+    }
+
+    let mut clusters: BTreeMap<usize, Vec<&Content>> = BTreeMap::new();
+
+    for (i, content) in items.iter().enumerate() {
+        clusters
+            .entry(root(&mut parents, i))
+            .or_default()
+            .push(content);
+    }
+
+    clusters
+        .into_values()
+        .filter(|cluster| cluster.len() > 1)
+        .collect()
+}
+
+/// Finds the most frequent [`Ressort`] of the given [Content].
+///
+/// Items without a ressort or with [`Ressort::None`] are skipped. Ties are resolved in favor of the ressort declared
+/// first in [`Ressort`]. Returns `None` if no item has a ressort.
+pub fn dominant_ressort(items: &[Content]) -> Option<Ressort> {
+    let mut counts: BTreeMap<Ressort, usize> = BTreeMap::new();
+
+    for ressort in items.iter().filter_map(|content| content.ressort()) {
+        if ressort != Ressort::None {
+            *counts.entry(ressort).or_default() += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|(ressort, count)| (*count, std::cmp::Reverse(*ressort)))
+        .map(|(ressort, _)| ressort)
+}
+
+/// Finds the articles directly before and after `current` in the given date-sorted [Content].
+///
+/// `current` is looked up by its [Sophora ID](Content::sophora_id), falling back to its URL, so it doesn't need to
+/// be an element of `items`. Returns `None` for a missing neighbor at either end, and for both if `current` isn't
+/// part of `items`.
+pub fn neighbors<'a>(
+    items: &'a [Content],
+    current: &Content,
+) -> (Option<&'a Content>, Option<&'a Content>) {
+    let identity = current.identity();
+
+    match items
+        .iter()
+        .position(|content| content.identity() == identity)
+    {
+        Some(i) => (
+            i.checked_sub(1).and_then(|prev| items.get(prev)),
+            items.get(i + 1),
+        ),
+        None => (None, None),
+    }
+}
+
+/// Collects the [`Ressorts`](Ressort) that are present in the given [Content], in a stable order.
+///
+/// Items without a ressort or with [`Ressort::None`] are skipped.
+pub fn distinct_ressorts(items: &[Content]) -> BTreeSet<Ressort> {
+    items
+        .iter()
+        .filter_map(|content| content.ressort())
+        .filter(|ressort| *ressort != Ressort::None)
+        .collect()
+}
+
+/// Collects every URL contained in the given [Content]: article, share, image variant and stream URLs.
+///
+/// Relative article URLs are resolved against tagesschau.de like [`TextArticle::canonical_url`], other values that
+/// are not valid absolute URLs are skipped. Every URL is only returned once.
+pub fn collect_urls(items: &[Content]) -> Vec<Url> {
+    let mut urls: Vec<Url> = Vec::new();
+    let mut seen: HashSet<Url> = HashSet::new();
+
+    fn sorted_values(map: Option<&HashMap<String, String>>) -> Vec<&str> {
+        let mut values: Vec<&str> = map
+            .into_iter()
+            .flat_map(|map| map.values().map(|value| value.as_str()))
+            .collect();
+        values.sort();
+        values
+    }
+
+    for item in items {
+        let mut article_url: Option<Url> = None;
+        let mut candidates: Vec<&str> = Vec::new();
+
+        match item {
+            Content::TextArticle(t) => {
+                article_url = t.canonical_url().ok();
+                candidates.extend(t.share_url.as_deref());
+            }
+            Content::Video(v) => {
+                candidates.extend(v.share_url.as_deref());
+                candidates.extend(sorted_values(Some(&v.streams)));
+            }
+        }
+
+        if let Some(image) = item.as_view().image {
+            candidates.extend(sorted_values(image.image_variants.as_ref()));
+        }
+
+        let parsed = candidates
+            .into_iter()
+            .filter_map(|candidate| Url::parse(candidate).ok());
+
+        for url in article_url.into_iter().chain(parsed) {
+            if seen.insert(url.clone()) {
+                urls.push(url);
+            }
+        }
+    }
+
+    urls
+}
+
+/// Writes the given [Content] as newline-delimited JSON, one article per line.
+///
+/// The fields keep the names used by the API, so every line can be deserialized into [Content] again.
+pub fn export_ndjson(items: &[Content], mut writer: impl Write) -> Result<(), Error> {
+    for item in items {
+        serde_json::to_writer(&mut writer, item).map_err(Error::SerializationError)?;
+        writer.write_all(b"\n")?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes the date, ressort, title and URL of the given [Content] as CSV, one article per row.
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub fn export_csv(items: &[Content], writer: impl Write) -> Result<(), Error> {
+    let mut writer = csv::Writer::from_writer(writer);
+
+    writer
+        .write_record(["date", "ressort", "title", "url"])
+        .map_err(std::io::Error::from)?;
+
+    for item in items {
+        let view = item.as_view();
+        let date = match item {
+            Content::TextArticle(t) => t.date_raw(),
+            Content::Video(v) => v.date_raw(),
+        };
+        let ressort = view.ressort.map(|r| r.to_string()).unwrap_or_default();
+
+        writer
+            .write_record([
+                date,
+                ressort.as_str(),
+                view.title,
+                view.url.unwrap_or_default(),
+            ])
+            .map_err(std::io::Error::from)?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Removes HTML tags and decodes HTML entities.
+///
+/// Only a `<` followed by a letter, `/` or `!` starts a tag, so comparisons like `< 2 Prozent` are kept.
+fn strip_html(s: &str) -> String {
+    let mut text = String::with_capacity(s.len());
+    let mut in_tag = false;
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let starts_tag = c == '<'
+            && chars
+                .peek()
+                .is_some_and(|next| next.is_ascii_alphabetic() || matches!(next, '/' | '!'));
+
+        match c {
+            _ if starts_tag => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    html_escape::decode_html_entities(&text).into_owned()
+}
+
+/// Keeps only the [Content] published within the given duration before now.
+///
+/// The cutoff is an absolute point in time, so it is computed in UTC and works even when the local offset
+/// cannot be determined.
+fn retain_recent(content: &mut Vec<Content>, within: Duration) {
+    let cutoff = OffsetDateTime::now_utc() - within;
+
+    content.retain(|content| content.date() >= cutoff);
+}
+
+/// Keeps only the [Content] whose title, topline or first sentence contains `query`, ignoring case.
+fn retain_matching(content: &mut Vec<Content>, query: &str) {
+    let query = query.to_lowercase();
+
+    content.retain(|content| {
+        let (title, topline, first_sentence) = match content {
+            Content::TextArticle(t) => (&t.title, &t.topline, Some(&t.first_sentence)),
+            Content::Video(v) => (&v.title, &v.topline, None),
+        };
+
+        title.to_lowercase().contains(&query)
+            || topline
+                .as_ref()
+                .is_some_and(|topline| topline.to_lowercase().contains(&query))
+            || first_sentence.is_some_and(|sentence| sentence.to_lowercase().contains(&query))
+    });
+}
+
+/// Splits the given [Content] into text articles and videos, keeping their order.
+fn split(content: Vec<Content>) -> (Vec<TextArticle>, Vec<Video>) {
+    let mut texts: Vec<TextArticle> = Vec::new();
+    let mut videos: Vec<Video> = Vec::new();
+
+    for content in content {
+        match content {
+            Content::TextArticle(t) => texts.push(t),
+            Content::Video(v) => videos.push(v),
+        }
+    }
+
+    (texts, videos)
+}
+
+/// Returns the results of a query that doesn't return [Content], failing with [`Error::Multiple`] if any request
+/// failed with [`ErrorMode::Collect`].
+fn collected<T>(items: T, errors: Vec<Error>) -> Result<T, Error> {
+    if errors.is_empty() {
+        Ok(items)
+    } else {
+        Err(Error::Multiple {
+            errors,
+            content: Vec::new(),
+        })
+    }
+}
+
+/// Sorts breaking news to the front, then orders by date descending.
+fn prioritize(content: &mut [Content]) {
+    content.sort_by(|element, next| {
+        next.is_breaking()
+            .cmp(&element.is_breaking())
+            .then_with(|| next.date().cmp(&element.date()))
+    });
+}
+
+#[derive(Deserialize, Debug)]
+struct Articles<T = Content> {
+    news: Vec<T>,
+    /// Regional articles, only present when a region filter is set.
+    #[serde(default = "Vec::new")]
+    regional: Vec<T>,
+}
+
+impl Articles {
+    /// Records the position of every article in the response before they get re-sorted.
+    fn assign_order(&mut self) {
+        for (order, content) in self.news.iter_mut().chain(&mut self.regional).enumerate() {
+            match content {
+                Content::TextArticle(t) => t.order = order,
+                Content::Video(v) => v.order = order,
+            }
+        }
+    }
+
+    /// Merges the regional articles into the national ones.
+    ///
+    /// Articles that are relevant nationally can appear in both arrays, so regional articles with the same
+    /// [identity](Content::identity) as a national one are dropped.
+    fn into_content(self) -> Vec<Content> {
+        let national: HashSet<String> = self.news.iter().map(Content::identity).collect();
+
+        let mut content = self.news;
+        content.extend(
+            self.regional
+                .into_iter()
+                .filter(|article| !national.contains(&article.identity())),
+        );
+
+        content
+    }
+}
+
+/// A value returned by the [TRequestBuilder] that can be either a text article or a video.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
 pub enum Content {
     #[allow(missing_docs)]
     TextArticle(TextArticle),
@@ -451,57 +2910,394 @@ impl Content {
         }
     }
 
-    /// Checks if the `Content` is a [`Video`].
-    pub fn is_video(&self) -> bool {
-        match self {
-            Content::TextArticle(_) => false,
-            Content::Video(_) => true,
-        }
+    /// Checks if the `Content` is a [`Video`].
+    pub fn is_video(&self) -> bool {
+        match self {
+            Content::TextArticle(_) => false,
+            Content::Video(_) => true,
+        }
+    }
+
+    fn date(&self) -> OffsetDateTime {
+        match self {
+            Content::TextArticle(t) => t.date.parsed,
+            Content::Video(v) => v.date.parsed,
+        }
+    }
+
+    /// Converts the publishing time to the given offset.
+    fn convert_offset(&mut self, offset: UtcOffset) {
+        let date = match self {
+            Content::TextArticle(t) => &mut t.date,
+            Content::Video(v) => &mut v.date,
+        };
+
+        date.parsed = date.parsed.to_offset(offset);
+    }
+
+    /// Strips HTML from the title, topline and first sentence.
+    fn strip_html(&mut self) {
+        let (title, topline, first_sentence) = match self {
+            Content::TextArticle(t) => (&mut t.title, &mut t.topline, Some(&mut t.first_sentence)),
+            Content::Video(v) => (&mut v.title, &mut v.topline, None),
+        };
+
+        *title = strip_html(title);
+
+        if let Some(topline) = topline {
+            *topline = strip_html(topline);
+        }
+
+        if let Some(first_sentence) = first_sentence {
+            *first_sentence = strip_html(first_sentence);
+        }
+    }
+
+    /// Get the Sophora ID of the article, see [`TextArticle::sophora_id`].
+    pub fn sophora_id(&self) -> Option<&str> {
+        match self {
+            Content::TextArticle(t) => t.sophora_id(),
+            Content::Video(v) => v.sophora_id(),
+        }
+    }
+
+    /// Get the URL of an image suited for link previews, the `16x9` variant of the attached [Image] whose width is
+    /// closest to 1200 pixels.
+    ///
+    /// Falls back to other aspect ratios if there is no `16x9` variant and returns `None` if there is no image.
+    pub fn og_image(&self) -> Option<&str> {
+        self.as_view().image?.closest_variant((16, 9), 1200)
+    }
+
+    /// Collects the searchable fields of the article for a full-text index, keyed by `title`, `topline`,
+    /// `first_sentence`, `tags`, `ressort` and `date`.
+    ///
+    /// The tags are joined with spaces and the date is kept as sent by the API. Fields the article doesn't have are
+    /// left out.
+    #[cfg(feature = "search-index")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search-index")))]
+    pub fn to_index_doc(&self) -> BTreeMap<&str, String> {
+        let view = self.as_view();
+        let mut doc: BTreeMap<&str, String> = BTreeMap::new();
+
+        doc.insert("title", view.title.to_string());
+
+        if let Some(topline) = view.topline {
+            doc.insert("topline", topline.to_string());
+        }
+
+        if let Content::TextArticle(t) = self {
+            doc.insert("first_sentence", t.first_sentence().to_string());
+        }
+
+        if let Some(tags) = view.tags {
+            doc.insert("tags", tags.join(" "));
+        }
+
+        if let Some(ressort) = view.ressort.filter(|ressort| *ressort != Ressort::None) {
+            doc.insert("ressort", ressort.as_api_str().to_string());
+        }
+
+        let date = match self {
+            Content::TextArticle(t) => t.date_raw(),
+            Content::Video(v) => v.date_raw(),
+        };
+        doc.insert("date", date.to_string());
+
+        doc
+    }
+
+    /// Identifies the article by its Sophora ID, falling back to its URL or its title and date.
+    fn identity(&self) -> String {
+        if let Some(id) = self.sophora_id() {
+            return id.to_string();
+        }
+
+        let view = self.as_view();
+
+        match view.url {
+            Some(url) => url.to_string(),
+            None => format!("{}@{}", view.title, view.date),
+        }
+    }
+
+    fn content_kind(&self) -> ContentKind {
+        match self {
+            Content::TextArticle(t) => t.content_kind(),
+            Content::Video(v) => v.content_kind(),
+        }
+    }
+
+    /// Get the [`Regions`](Region) the article belongs to, empty for national news.
+    pub fn regions(&self) -> BTreeSet<Region> {
+        match self {
+            Content::TextArticle(t) => t.regions(),
+            Content::Video(v) => v.regions(),
+        }
+    }
+
+    fn ressort(&self) -> Option<Ressort> {
+        match self {
+            Content::TextArticle(t) => t.ressort,
+            Content::Video(v) => v.ressort,
+        }
+    }
+
+    fn is_breaking(&self) -> bool {
+        let breaking_news = match self {
+            Content::TextArticle(t) => t.breaking_news,
+            Content::Video(v) => v.breaking_news,
+        };
+
+        breaking_news == Some(true)
+    }
+
+    /// Get a uniform [`ArticleView`] of this `Content`.
+    pub fn as_view(&self) -> ArticleView<'_> {
+        match self {
+            Content::TextArticle(t) => ArticleView {
+                title: t.title(),
+                topline: t.topline(),
+                date: t.date(),
+                url: Some(t.url()),
+                image: t.image(),
+                ressort: t.ressort(),
+                breaking_news: t.breaking_news(),
+                tags: t.tags(),
+                kind: t.kind(),
+            },
+            Content::Video(v) => ArticleView {
+                title: v.title(),
+                topline: v.topline(),
+                date: v.date(),
+                url: v
+                    .share_url()
+                    .or_else(|| v.streams.get("adaptivestreaming").map(|s| s.as_str())),
+                image: v.image(),
+                ressort: v.ressort(),
+                breaking_news: v.breaking_news(),
+                tags: v.tags(),
+                kind: v.kind(),
+            },
+        }
+    }
+
+    /// Unpacks a and returns a [`TextArticle`].
+    pub fn to_text(self) -> Result<TextArticle, Error> {
+        match self {
+            Content::TextArticle(text) => Ok(text),
+            Content::Video(_) => Err(Error::ConversionError),
+        }
+    }
+
+    /// Unpacks a and returns a [`Video`].
+    pub fn to_video(self) -> Result<Video, Error> {
+        match self {
+            Content::Video(video) => Ok(video),
+            Content::TextArticle(_) => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl Display for Content {
+    /// Formats the content as a compact single line in the form `[<date>] <ressort>: <title>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self
+            .date()
+            .format(format_description!("[year]-[month]-[day] [hour]:[minute]"))
+            .map_err(|_| fmt::Error)?;
+
+        let title = match self {
+            Content::TextArticle(t) => &t.title,
+            Content::Video(v) => &v.title,
+        };
+
+        match self.ressort() {
+            Some(ressort) if ressort != Ressort::None => {
+                write!(f, "[{}] {}: {}", date, ressort, title)
+            }
+            _ => write!(f, "[{}] {}", date, title),
+        }
+    }
+}
+
+/// The fields of an article needed by [`TRequestBuilder::get_counts_per_day`] to filter it like [Content].
+#[derive(Deserialize, Debug)]
+struct CountedArticle {
+    title: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(rename = "breakingNews")]
+    breaking_news: Option<bool>,
+}
+
+/// The fields of an article needed to identify it like [`Content::identity`].
+#[derive(Deserialize, Debug)]
+struct ArticleKey {
+    #[serde(rename = "sophoraId")]
+    sophora_id: Option<String>,
+    #[serde(rename = "detailsweb")]
+    url: Option<String>,
+    #[serde(rename = "shareURL")]
+    share_url: Option<String>,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    date: String,
+}
+
+impl ArticleKey {
+    fn identity(&self) -> String {
+        match (&self.sophora_id, &self.url, &self.share_url) {
+            (Some(id), _, _) => id.clone(),
+            (None, Some(url), _) | (None, None, Some(url)) => url.clone(),
+            (None, None, None) => format!("{}@{}", self.title, self.date),
+        }
+    }
+}
+
+/// A minimal article returned by [`TRequestBuilder::get_headlines`], containing only the fields needed for an index.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Headline {
+    title: String,
+    #[serde(with = "rfc3339")]
+    date: OffsetDateTime,
+    #[serde(rename = "detailsweb")]
+    url: Option<String>,
+    ressort: Option<Ressort>,
+}
+
+impl Headline {
+    /// Get the title of this `Headline`.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the publishing time of this `Headline` as [OffsetDateTime].
+    pub fn date(&self) -> OffsetDateTime {
+        self.date
+    }
+
+    /// Get the URL to the article of this `Headline`, not present for videos.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Get the [`Ressort`] of this `Headline`.
+    pub fn ressort(&self) -> Option<Ressort> {
+        self.ressort
+    }
+}
+
+/// A [Content] together with the [`Ressort`] it was requested under.
+#[derive(Debug)]
+pub struct TaggedContent {
+    requested_ressort: Ressort,
+    content: Content,
+}
+
+impl TaggedContent {
+    /// Get the [`Ressort`] this `TaggedContent` was requested under.
+    pub fn requested_ressort(&self) -> Ressort {
+        self.requested_ressort
     }
 
-    /// Unpacks a and returns a [`TextArticle`].
-    pub fn to_text(self) -> Result<TextArticle, Error> {
-        match self {
-            Content::TextArticle(text) => Ok(text),
-            Content::Video(_) => Err(Error::ConversionError),
-        }
+    /// Get the [Content] of this `TaggedContent`.
+    pub fn content(&self) -> &Content {
+        &self.content
     }
 
-    /// Unpacks a and returns a [`Video`].
-    pub fn to_video(self) -> Result<Video, Error> {
-        match self {
-            Content::Video(video) => Ok(video),
-            Content::TextArticle(_) => Err(Error::ConversionError),
-        }
+    /// Consumes this `TaggedContent` and returns the [Content].
+    pub fn into_content(self) -> Content {
+        self.content
     }
 }
 
+/// A uniform, borrowed view of a [Content] that does not distinguish between text articles and videos.
+#[derive(Debug, Clone)]
+pub struct ArticleView<'a> {
+    /// The title of the article.
+    pub title: &'a str,
+    /// The topline of the article.
+    pub topline: Option<&'a str>,
+    /// The publishing time of the article.
+    pub date: OffsetDateTime,
+    /// The URL to the article. For videos this is the share URL, falling back to the adaptive stream.
+    pub url: Option<&'a str>,
+    /// The image attached to the article.
+    pub image: Option<&'a Image>,
+    /// The [`Ressort`] of the article.
+    pub ressort: Option<Ressort>,
+    /// If the article is breaking news or not.
+    pub breaking_news: Option<bool>,
+    /// The tags of the article.
+    pub tags: Option<Vec<&'a str>>,
+    /// The type of article this is.
+    pub kind: &'a str,
+}
+
 /// A text article returned by the API.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct TextArticle {
+    #[serde(rename = "sophoraId")]
+    sophora_id: Option<String>,
+    #[serde(
+        rename = "regionId",
+        default,
+        deserialize_with = "lenient_region_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    region_id: Vec<u8>,
+    #[serde(
+        rename = "regionIds",
+        default,
+        deserialize_with = "lenient_region_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    region_ids: Vec<u8>,
     title: String,
-    #[serde(rename(deserialize = "firstSentence"))]
+    topline: Option<String>,
+    #[serde(rename = "firstSentence")]
     first_sentence: String,
-    #[serde(with = "rfc3339")]
-    date: OffsetDateTime,
-    #[serde(rename(deserialize = "detailsweb"))]
+    date: ApiDate,
+    #[serde(rename = "detailsweb")]
     url: String,
+    #[serde(rename = "shareURL")]
+    share_url: Option<String>,
     tags: Option<Vec<Tag>>,
     ressort: Option<Ressort>,
-    #[serde(rename(deserialize = "type"))]
+    #[serde(rename = "type")]
     kind: String,
-    #[serde(rename(deserialize = "breakingNews"))]
+    #[serde(rename = "breakingNews")]
     breaking_news: Option<bool>,
-    #[serde(rename(deserialize = "teaserImage"))]
+    #[serde(rename = "teaserImage")]
     image: Option<Image>,
+    #[serde(rename = "externalId")]
+    external_id: Option<String>,
+    #[serde(default, deserialize_with = "lenient_string")]
+    comments: Option<String>,
+    #[serde(default, deserialize_with = "lenient_streams")]
+    streams: Option<HashMap<String, String>>,
+    #[serde(skip)]
+    order: usize,
 }
 
 impl TextArticle {
+    /// Get the Sophora ID, the identifier used by the Tagesschau's content management system, of this `TextArticle`.
+    pub fn sophora_id(&self) -> Option<&str> {
+        self.sophora_id.as_deref()
+    }
+
     /// Get the title of this `TextArticle`.
     pub fn title(&self) -> &str {
         &self.title
     }
 
+    /// Get the topline of this `TextArticle`.
+    pub fn topline(&self) -> Option<&str> {
+        self.topline.as_deref()
+    }
+
     /// Get the first sentence of this `TextArticle`.
     pub fn first_sentence(&self) -> &str {
         &self.first_sentence
@@ -509,7 +3305,12 @@ impl TextArticle {
 
     /// Get the publishing time of this `TextArticle` as [OffsetDateTime].
     pub fn date(&self) -> OffsetDateTime {
-        self.date
+        self.date.parsed
+    }
+
+    /// Get the publishing time of this `TextArticle` exactly as sent by the API.
+    pub fn date_raw(&self) -> &str {
+        &self.date.raw
     }
 
     /// Get the URL to this `TextArticle`.
@@ -517,6 +3318,49 @@ impl TextArticle {
         &self.url
     }
 
+    /// Get the URL to share this `TextArticle`.
+    pub fn share_url(&self) -> Option<&str> {
+        self.share_url.as_deref()
+    }
+
+    /// Get the (stream-type, URL) (key, value) pairs of the video or audio embedded in this `TextArticle`, if any.
+    pub fn streams(&self) -> Option<HashMap<&str, &str>> {
+        self.streams.as_ref().map(|streams| {
+            streams
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect()
+        })
+    }
+
+    /// Get the external ID of this `TextArticle`, which identifies it in other systems such as the comment section.
+    pub fn external_id(&self) -> Option<&str> {
+        self.external_id.as_deref()
+    }
+
+    /// Get the URL to the discussion of this `TextArticle`, if comments are enabled.
+    pub fn comments_url(&self) -> Option<&str> {
+        self.comments.as_deref()
+    }
+
+    /// Checks if comments are enabled for this `TextArticle`.
+    pub fn has_comments(&self) -> bool {
+        self.comments.is_some()
+    }
+
+    /// Get the URL to this `TextArticle` as an absolute [Url], resolving relative paths against tagesschau.de.
+    pub fn canonical_url(&self) -> Result<Url, Error> {
+        Ok(Url::parse(BASE_URL)?.join(&self.url)?)
+    }
+
+    /// Follow the redirects of the [canonical URL](TextArticle::canonical_url) of this `TextArticle` with a `HEAD`
+    /// request and return the final URL.
+    pub async fn resolve_url(&self, client: &reqwest::Client) -> Result<Url, Error> {
+        let url = self.canonical_url()?;
+
+        resolve_redirect(client.head(url)).await
+    }
+
     /// Get the tags of this `TextArticle`.
     pub fn tags(&self) -> Option<Vec<&str>> {
         match &self.tags {
@@ -536,16 +3380,53 @@ impl TextArticle {
         self.ressort
     }
 
+    /// Get the [`Regions`](Region) this `TextArticle` belongs to, empty for national news.
+    pub fn regions(&self) -> BTreeSet<Region> {
+        regions_from_ids(self.region_id.iter().chain(&self.region_ids))
+    }
+
     /// Get the type of `TextArticle` this is.
     pub fn kind(&self) -> &str {
         &self.kind
     }
 
+    /// Get the type of `TextArticle` this is as a [`ContentKind`].
+    pub fn content_kind(&self) -> ContentKind {
+        ContentKind::from_api_str(&self.kind)
+    }
+
+    /// Checks if this `TextArticle` is a regular article, with the type `"story"`.
+    pub fn is_story(&self) -> bool {
+        self.content_kind() == ContentKind::Story
+    }
+
+    /// Checks if this `TextArticle` is a live blog, with the type `"liveblog"`.
+    pub fn is_liveblog(&self) -> bool {
+        self.content_kind() == ContentKind::Liveblog
+    }
+
+    /// Checks if this `TextArticle` is a photo gallery, with the type `"gallery"`.
+    pub fn is_gallery(&self) -> bool {
+        self.content_kind() == ContentKind::Gallery
+    }
+
+    /// Checks if this `TextArticle` is an embedded web page, with the type `"webview"`.
+    pub fn is_webview(&self) -> bool {
+        self.content_kind() == ContentKind::Webview
+    }
+
     /// Get if this `TextArticle` is breaking news or not.
     pub fn breaking_news(&self) -> Option<bool> {
         self.breaking_news
     }
 
+    /// Get the position of this `TextArticle` in the API response, reflecting its editorial ranking.
+    ///
+    /// Regional articles are counted after the regular ones.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
     /// Get the image attached to this `TextArticle`.
     pub fn image(&self) -> Option<&Image> {
         self.image.as_ref()
@@ -553,42 +3434,119 @@ impl TextArticle {
 }
 
 /// A video returned by the API.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct Video {
+    #[serde(rename = "sophoraId")]
+    sophora_id: Option<String>,
+    #[serde(
+        rename = "regionId",
+        default,
+        deserialize_with = "lenient_region_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    region_id: Vec<u8>,
+    #[serde(
+        rename = "regionIds",
+        default,
+        deserialize_with = "lenient_region_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    region_ids: Vec<u8>,
     title: String,
-    #[serde(with = "rfc3339")]
-    date: OffsetDateTime,
+    topline: Option<String>,
+    date: ApiDate,
+    #[serde(rename = "shareURL")]
+    share_url: Option<String>,
     streams: HashMap<String, String>,
     tags: Option<Vec<Tag>>,
     ressort: Option<Ressort>,
-    #[serde(rename(deserialize = "type"))]
+    #[serde(rename = "type")]
     kind: String,
-    #[serde(rename(deserialize = "breakingNews"))]
+    #[serde(rename = "breakingNews")]
     breaking_news: Option<bool>,
-    #[serde(rename(deserialize = "teaserImage"))]
+    #[serde(rename = "teaserImage")]
     image: Option<Image>,
+    #[serde(skip)]
+    order: usize,
 }
 
 impl Video {
+    /// Get the Sophora ID, the identifier used by the Tagesschau's content management system, of this `Video`.
+    pub fn sophora_id(&self) -> Option<&str> {
+        self.sophora_id.as_deref()
+    }
+
     /// Get the title of this `Video`.
     pub fn title(&self) -> &str {
         &self.title
     }
 
+    /// Get the topline of this `Video`.
+    pub fn topline(&self) -> Option<&str> {
+        self.topline.as_deref()
+    }
+
     /// Get the publishing time of this `Video` as [OffsetDateTime].
     pub fn date(&self) -> OffsetDateTime {
-        self.date
+        self.date.parsed
+    }
+
+    /// Get the publishing time of this `Video` exactly as sent by the API.
+    pub fn date_raw(&self) -> &str {
+        &self.date.raw
+    }
+
+    /// Get the URL to share this `Video`.
+    pub fn share_url(&self) -> Option<&str> {
+        self.share_url.as_deref()
     }
 
     /// Get the [`HashMap`] consisting of (stream-type, URL) (key, value) pairs of this `Video`.
+    ///
+    /// The iteration order is not guaranteed, use [`streams_sorted`](Video::streams_sorted) for a reproducible order.
     pub fn streams(&self) -> HashMap<&str, &str> {
         let mut streams: HashMap<&str, &str> = HashMap::new();
         for (key, value) in &self.streams {
-            streams.insert(&key, &value);
+            streams.insert(key, value);
         }
         streams
     }
 
+    /// Get the (stream-type, URL) (key, value) pairs of this `Video`, sorted by stream-type.
+    pub fn streams_sorted(&self) -> BTreeMap<&str, &str> {
+        self.streams
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// Get the (stream-type, [Url]) (key, value) pairs of this `Video`.
+    ///
+    /// Streams with an invalid URL are skipped and logged as a [tracing] warning, use
+    /// [`try_stream_urls`](Video::try_stream_urls) to fail on them instead.
+    pub fn stream_urls(&self) -> HashMap<String, Url> {
+        self.streams
+            .iter()
+            .filter_map(|(key, value)| match Url::parse(value) {
+                Ok(url) => Some((key.clone(), url)),
+                Err(e) => {
+                    tracing::warn!("Invalid URL `{}` of stream `{}`: {}", value, key, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Get the (stream-type, [Url]) (key, value) pairs of this `Video`.
+    ///
+    /// Fails with [`Error::UrlParsing`] if any stream has an invalid URL.
+    pub fn try_stream_urls(&self) -> Result<HashMap<String, Url>, Error> {
+        self.streams
+            .iter()
+            .map(|(key, value)| Ok((key.clone(), Url::parse(value)?)))
+            .collect()
+    }
+
     /// Get the tags of this `Video`.
     pub fn tags(&self) -> Option<Vec<&str>> {
         match &self.tags {
@@ -608,23 +3566,43 @@ impl Video {
         self.ressort
     }
 
+    /// Get the [`Regions`](Region) this `Video` belongs to, empty for national news.
+    pub fn regions(&self) -> BTreeSet<Region> {
+        regions_from_ids(self.region_id.iter().chain(&self.region_ids))
+    }
+
     /// Get the type of `Video` this is.
     pub fn kind(&self) -> &str {
         &self.kind
     }
 
+    /// Get the type of `Video` this is as a [`ContentKind`].
+    pub fn content_kind(&self) -> ContentKind {
+        ContentKind::from_api_str(&self.kind)
+    }
+
     /// Get if this `Video` is breaking news or not.
     pub fn breaking_news(&self) -> Option<bool> {
         self.breaking_news
     }
 
+    /// Get the position of this `Video` in the API response, reflecting its editorial ranking.
+    ///
+    /// Regional articles are counted after the regular ones.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
     /// Get the image attached to this `Video`.
     pub fn image(&self) -> Option<&Image> {
         match &self.image {
             Some(img) => match &img.image_variants {
                 Some(variants) => {
-                    let var = if variants.is_empty() { None } else { Some(img) };
-                    var
+                    if variants.is_empty() {
+                        None
+                    } else {
+                        Some(img)
+                    }
                 }
                 None => None,
             },
@@ -634,20 +3612,50 @@ impl Video {
     }
 }
 
-#[derive(Deserialize, Debug)]
+/// An RFC3339 date sent by the API, keeping the original string next to the parsed value.
+///
+/// It is serialized as the original string.
+#[derive(Debug)]
+struct ApiDate {
+    raw: String,
+    parsed: OffsetDateTime,
+}
+
+impl Serialize for ApiDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        let parsed = OffsetDateTime::parse(&raw, &Rfc3339).map_err(de::Error::custom)?;
+
+        Ok(ApiDate { raw, parsed })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
 struct Tag {
     tag: String,
 }
 
 /// A struct that contains an images metadata and variants.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Image {
     title: Option<String>,
     copyright: Option<String>,
     alttext: Option<String>,
-    #[serde(rename(deserialize = "imageVariants"))]
+    #[serde(rename = "imageVariants")]
     image_variants: Option<HashMap<String, String>>,
-    #[serde(rename(deserialize = "type"))]
+    #[serde(rename = "type")]
     kind: String,
 }
 
@@ -655,7 +3663,7 @@ impl Image {
     /// Get the title of this `Image`.
     pub fn title(&self) -> Option<&str> {
         match &self.title {
-            Some(title) => Some(&title),
+            Some(title) => Some(title),
             None => None,
         }
     }
@@ -663,25 +3671,74 @@ impl Image {
     /// Get the copyright of this `Image`.
     pub fn copyright(&self) -> Option<&str> {
         match &self.copyright {
-            Some(copyright) => Some(&copyright),
+            Some(copyright) => Some(copyright),
             None => None,
         }
     }
 
+    /// Get the copyright of this `Image` parsed into a [`Credit`], on a best-effort basis.
+    ///
+    /// Copyrights like `"dpa | Foto: Max Mustermann"` are split on `|` and `Foto:` into agency and photographer.
+    /// If the copyright doesn't follow these conventions it is returned as the agency.
+    pub fn credit(&self) -> Option<Credit> {
+        let copyright = self.copyright.as_deref()?.trim();
+
+        if copyright.is_empty() {
+            return None;
+        }
+
+        let non_empty = |s: &str| {
+            let s = s.trim();
+            (!s.is_empty()).then(|| s.to_string())
+        };
+
+        let (agency, photographer) = if let Some((agency, photographer)) = copyright.split_once('|')
+        {
+            let photographer = photographer.trim();
+            let photographer = photographer.strip_prefix("Foto:").unwrap_or(photographer);
+
+            (non_empty(agency), non_empty(photographer))
+        } else if let Some((agency, photographer)) = copyright.split_once("Foto:") {
+            (
+                non_empty(agency.trim_end_matches([' ', ',', '/'])),
+                non_empty(photographer),
+            )
+        } else {
+            (Some(copyright.to_string()), None)
+        };
+
+        Some(Credit {
+            agency,
+            photographer,
+        })
+    }
+
     /// Get the alt-text of this `Image`.
     pub fn alttext(&self) -> Option<&str> {
         match &self.alttext {
-            Some(alttext) => Some(&alttext),
+            Some(alttext) => Some(alttext),
             None => None,
         }
     }
 
+    /// Get the (image-resolution, URL) (key, value) pairs of this `Image`, sorted by image-resolution.
+    pub fn image_variants_sorted(&self) -> BTreeMap<&str, &str> {
+        self.image_variants
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+
     /// Get the [`HashMap`] consisting of (image-resolution, URL) (key, value) pairs of this `Image`.
+    ///
+    /// The iteration order is not guaranteed, use [`image_variants_sorted`](Image::image_variants_sorted) for a
+    /// reproducible order.
     pub fn image_variants(&self) -> HashMap<&str, &str> {
         let variants = self.image_variants.as_ref().unwrap();
         let mut image_variants: HashMap<&str, &str> = HashMap::new();
         for (key, value) in variants {
-            image_variants.insert(&key, &value);
+            image_variants.insert(key, value);
         }
         image_variants
     }
@@ -690,33 +3747,574 @@ impl Image {
     pub fn kind(&self) -> &str {
         &self.kind
     }
+
+    /// Download the given variant of this `Image` into memory using the provided [`reqwest::Client`].
+    ///
+    /// If no variant is specified, the widest available variant is used.
+    pub async fn bytes(
+        &self,
+        client: &reqwest::Client,
+        variant: Option<&str>,
+    ) -> Result<ImageData, Error> {
+        let url = self.variant_url(variant)?;
+
+        let response = client.get(url).send().await.map_err(Error::BadRequest)?;
+
+        if response.status() != StatusCode::OK {
+            return Err(Error::InvalidResponse(response.status().as_u16()));
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+
+        let bytes = response.bytes().await.map_err(Error::ParsingError)?;
+
+        Ok(ImageData {
+            bytes: bytes.to_vec(),
+            content_type,
+        })
+    }
+
+    /// Get the URL of the widest variant of this `Image` with the given aspect ratio, e.g. `16` by `9` for the
+    /// `16x9-*` variants.
+    ///
+    /// Returns `None` if the aspect ratio isn't offered.
+    pub fn variant_for_ratio(&self, w: u32, h: u32) -> Option<&str> {
+        self.image_variants
+            .as_ref()?
+            .iter()
+            .filter_map(|(key, url)| {
+                let (ratio_w, ratio_h, width) = parse_variant_key(key)?;
+
+                (ratio_w == w && ratio_h == h).then_some((width, url))
+            })
+            .max_by_key(|(width, _)| *width)
+            .map(|(_, url)| url.as_str())
+    }
+
+    /// Get the URL of the variant whose width is closest to `width`, preferring the given aspect ratio.
+    ///
+    /// Ties are resolved in favor of the wider variant.
+    fn closest_variant(&self, ratio: (u32, u32), width: u32) -> Option<&str> {
+        self.image_variants
+            .as_ref()?
+            .iter()
+            .filter_map(|(key, url)| {
+                let (ratio_w, ratio_h, variant_width) = parse_variant_key(key)?;
+                let other_ratio = (ratio_w, ratio_h) != ratio;
+
+                Some((
+                    (
+                        other_ratio,
+                        variant_width.abs_diff(width),
+                        u32::MAX - variant_width,
+                    ),
+                    url,
+                ))
+            })
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, url)| url.as_str())
+    }
+
+    /// Resolves the URL of the given variant, or of the widest variant if none is specified.
+    fn variant_url(&self, variant: Option<&str>) -> Result<&str, Error> {
+        let variants = self.image_variants.as_ref().ok_or(Error::VariantNotFound)?;
+
+        let url = match variant {
+            Some(key) => variants.get(key),
+            None => variants
+                .iter()
+                .max_by_key(|(key, _)| parse_variant_key(key).map(|(_, _, width)| width))
+                .map(|(_, url)| url),
+        };
+
+        url.map(|url| url.as_str()).ok_or(Error::VariantNotFound)
+    }
+}
+
+/// Parses an image variant key like `16x9-1920` into its aspect ratio and width.
+fn parse_variant_key(key: &str) -> Option<(u32, u32, u32)> {
+    let (ratio, width) = key.split_once('-')?;
+    let (ratio_w, ratio_h) = ratio.split_once('x')?;
+
+    Some((
+        ratio_w.parse().ok()?,
+        ratio_h.parse().ok()?,
+        width.parse().ok()?,
+    ))
+}
+
+/// The media of an article, containing only the title, date, image and streams.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Media {
+    title: String,
+    #[serde(with = "rfc3339")]
+    date: OffsetDateTime,
+    #[serde(rename = "teaserImage")]
+    image: Option<Image>,
+    streams: Option<HashMap<String, String>>,
+}
+
+impl Media {
+    /// Get the title of this `Media`.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the publishing time of this `Media` as [OffsetDateTime].
+    pub fn date(&self) -> OffsetDateTime {
+        self.date
+    }
+
+    /// Get the [Image] of this `Media`.
+    pub fn image(&self) -> Option<&Image> {
+        self.image.as_ref()
+    }
+
+    /// Get the (stream-type, URL) pairs of this `Media`, only present for videos.
+    pub fn streams(&self) -> Option<&HashMap<String, String>> {
+        self.streams.as_ref()
+    }
+}
+
+/// The photo credit of an [`Image`], parsed from its copyright.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credit {
+    agency: Option<String>,
+    photographer: Option<String>,
+}
+
+impl Credit {
+    /// Get the agency of this `Credit`, e.g. `dpa`.
+    pub fn agency(&self) -> Option<&str> {
+        self.agency.as_deref()
+    }
+
+    /// Get the photographer of this `Credit`.
+    pub fn photographer(&self) -> Option<&str> {
+        self.photographer.as_deref()
+    }
+}
+
+/// An [`Image`] together with the title of the article it is attached to.
+#[derive(Debug, Clone)]
+pub struct ArticleImage {
+    article_title: String,
+    image: Image,
+}
+
+impl ArticleImage {
+    /// Get the title of the article this `ArticleImage` is attached to.
+    pub fn article_title(&self) -> &str {
+        &self.article_title
+    }
+
+    /// Get the [`Image`] of this `ArticleImage`.
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+}
+
+/// The raw bytes of a downloaded [`Image`] variant.
+#[derive(Debug, Clone)]
+pub struct ImageData {
+    bytes: Vec<u8>,
+    content_type: Option<String>,
+}
+
+impl ImageData {
+    /// Get the raw bytes of this `ImageData`.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Get the content type reported by the server for this `ImageData`, e.g. `image/jpeg`.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Consumes this `ImageData` and returns the raw bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
 }
 
 /// The Errors that might occur when using the API.
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     /// Fetching articles failed.
-    #[error("Fetching articles failed")]
-    BadRequest(reqwest::Error),
+    #[error("Fetching articles failed: {0}")]
+    BadRequest(#[source] reqwest::Error),
+    /// Connecting to the server timed out.
+    #[error("Connecting timed out: {0}")]
+    ConnectTimeout(#[source] reqwest::Error),
+    /// The request was redirected more often than allowed by the redirect policy.
+    #[error("Too many redirects: {0}")]
+    TooManyRedirects(#[source] reqwest::Error),
+    /// Failed to create the HTTP client.
+    #[error("Failed to create HTTP client: {0}")]
+    ClientError(#[source] reqwest::Error),
     /// Failed to parse http response.
-    #[error("Failed to parse response")]
-    ParsingError(reqwest::Error),
+    #[error("Failed to parse response: {0}")]
+    ParsingError(#[source] reqwest::Error),
     /// Invalid HTTP Response, contains HTTP response code.
     #[error("Invalid Response: HTTP Response Code {0}")]
     InvalidResponse(u16),
     /// Failed to deserialize response.
-    #[error("Failed to deserialize response")]
+    ///
+    /// This is no longer returned, deserialization errors are reported as [`Error::DeserializationFailed`].
+    #[deprecated(note = "deserialization errors are reported as `Error::DeserializationFailed`")]
+    #[error("Failed to deserialize response: {0}")]
     DeserializationError(#[from] serde_json::Error),
+    /// Failed to deserialize response, contains the JSON surrounding the error position.
+    #[error("Failed to deserialize response near `{snippet}`: {source}")]
+    DeserializationFailed {
+        /// The underlying deserialization error.
+        source: serde_json::Error,
+        /// The JSON surrounding the error position.
+        snippet: String,
+    },
+    /// Failed to serialize articles.
+    #[error("Failed to serialize articles: {0}")]
+    SerializationError(#[source] serde_json::Error),
     /// Tried to extract wrong type from [Content].
     #[error("Tried to extract wrong type")]
     ConversionError,
+    /// The string is not a date in a supported format.
+    #[error("Invalid date `{0}`, expected `YYYY-MM-DD` or `DD.MM.YYYY`")]
+    InvalidDateFormat(String),
     /// Unable to retrieve current date.
-    #[error("Unable to retrieve current date")]
+    #[error("Unable to retrieve current date: {0}")]
     DateError(#[from] time::error::IndeterminateOffset),
     /// Unable parse date.
-    #[error("Unable parse date")]
+    #[error("Unable parse date: {0}")]
     DateParsingError(#[from] time::error::ComponentRange),
+    /// No articles matched the specified filters for the queried dates.
+    #[error("No articles matched the specified filters ({filters})")]
+    NoResults {
+        /// The dates that were queried.
+        dates: Vec<TDate>,
+        /// A description of the ressort and regions that were applied.
+        filters: String,
+    },
+    /// The API sent an HTML page instead of JSON, usually an error page, contains the `Content-Type` if it was set.
+    #[error(
+        "Expected a JSON response, got {}",
+        content_type.as_deref().unwrap_or("an HTML page")
+    )]
+    UnexpectedContentType {
+        /// The `Content-Type` header of the response.
+        content_type: Option<String>,
+    },
+    /// The response exceeded the configured maximum size, contains the limit in bytes.
+    #[error("Response exceeded the maximum size of {0} bytes")]
+    ResponseTooLarge(usize),
+    /// The requested image variant does not exist.
+    #[error("Image variant not found")]
+    VariantNotFound,
+    /// Reading or writing data failed.
+    #[error("I/O operation failed: {0}")]
+    Io(#[from] std::io::Error),
+    /// One or more requests failed while collecting errors with [`ErrorMode::Collect`].
+    #[error("{} requests failed", errors.len())]
+    Multiple {
+        /// The errors of the failed requests.
+        errors: Vec<Error>,
+        /// The articles of the successful requests, sorted by date. Empty for queries that don't return [Content].
+        content: Vec<Content>,
+    },
+    /// The timeframe contains a date after the current date, contains the date.
+    #[error("Date {0} is in the future")]
+    DateInFuture(TDate),
+    /// The name or value of a header is invalid, contains the header name.
+    #[error("Invalid header `{0}`")]
+    InvalidHeader(String),
+    /// The specified filters can't be combined, contains the reason.
+    #[error("Incompatible filters: {0}")]
+    IncompatibleFilters(String),
+    /// The timeframe can't be used for the requested operation, contains the reason.
+    #[error("Invalid timeframe: {0}")]
+    InvalidTimeframe(String),
+    /// The deadline of the query expired before all dates were queried.
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
     /// URL parsing failed.
-    #[error("URL parsing failed")]
+    #[error("URL parsing failed: {0}")]
     UrlParsing(#[from] url::ParseError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn parse_response_rejects_html() {
+        let result =
+            parse_response::<serde_json::Value>("<html><body>Wartungsarbeiten</body></html>");
+
+        assert!(matches!(
+            result,
+            Err(Error::UnexpectedContentType { content_type: None })
+        ));
+    }
+
+    #[test]
+    fn check_content_type_rejects_html() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("text/html; charset=utf-8"),
+        );
+
+        match check_content_type(&headers) {
+            Err(Error::UnexpectedContentType {
+                content_type: Some(content_type),
+            }) => assert_eq!(content_type, "text/html; charset=utf-8"),
+            other => panic!("expected UnexpectedContentType, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_content_type_accepts_json() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        assert!(check_content_type(&headers).is_ok());
+    }
+
+    #[test]
+    fn regional_articles_are_merged() {
+        let articles = TRequestBuilder::new()
+            .parse_articles(include_str!("../tests/fixtures/regional.json"))
+            .unwrap();
+
+        let content = articles.into_content();
+        let titles: Vec<&str> = content
+            .iter()
+            .map(|content| content.as_view().title)
+            .collect();
+
+        assert_eq!(
+            titles,
+            [
+                "Bundestag beschließt Haushalt",
+                "Hochwasserlage in Bayern spitzt sich zu",
+                "Streik legt Nahverkehr in München lahm",
+            ]
+        );
+    }
+
+    #[test]
+    fn regional_headlines_are_merged() {
+        let headlines: Vec<Headline> =
+            parse_merged(include_str!("../tests/fixtures/regional.json")).unwrap();
+
+        let titles: Vec<&str> = headlines.iter().map(Headline::title).collect();
+
+        assert_eq!(
+            titles,
+            [
+                "Bundestag beschließt Haushalt",
+                "Hochwasserlage in Bayern spitzt sich zu",
+                "Streik legt Nahverkehr in München lahm",
+            ]
+        );
+    }
+
+    #[test]
+    fn counted_articles_match_content() {
+        let text = include_str!("../tests/fixtures/regional.json");
+
+        let mut builder = TRequestBuilder::new();
+        assert_eq!(builder.counted(parse_merged(text).unwrap()).len(), 3);
+
+        builder.breaking_only(true);
+        assert_eq!(builder.counted(parse_merged(text).unwrap()).len(), 1);
+    }
+
+    #[test]
+    fn ndjson_round_trips() {
+        let content = TRequestBuilder::new()
+            .parse_articles(include_str!("../tests/fixtures/regional.json"))
+            .unwrap()
+            .into_content();
+
+        let mut ndjson: Vec<u8> = Vec::new();
+        export_ndjson(&content, &mut ndjson).unwrap();
+
+        let parsed: Vec<Content> = std::str::from_utf8(&ndjson)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(parsed.len(), content.len());
+
+        for (parsed, original) in parsed.iter().zip(&content) {
+            assert_eq!(parsed.identity(), original.identity());
+            assert_eq!(parsed.date(), original.date());
+            assert_eq!(parsed.regions(), original.regions());
+            assert_eq!(parsed.content_kind(), original.content_kind());
+            assert_eq!(parsed.is_breaking(), original.is_breaking());
+        }
+    }
+
+    #[test]
+    fn strip_html_keeps_less_than_signs() {
+        assert_eq!(
+            strip_html("Inflation <b>sinkt</b> auf < 2 Prozent<!-- x -->"),
+            "Inflation sinkt auf < 2 Prozent"
+        );
+    }
+
+    #[test]
+    fn parse_iso_date() {
+        assert_eq!(
+            TDate::parse("2024-03-15").unwrap(),
+            TDate::from_calendar_date(2024, Month::March, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_german_date() {
+        assert_eq!(
+            TDate::parse("15.03.2024").unwrap(),
+            TDate::from_calendar_date(2024, Month::March, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_date_requires_four_digit_year() {
+        assert!(matches!(
+            TDate::parse("15-03-2024"),
+            Err(Error::InvalidDateFormat(s)) if s == "15-03-2024"
+        ));
+    }
+
+    #[test]
+    fn parse_date_rejects_invalid_month() {
+        assert!(matches!(
+            TDate::parse("2024-13-01"),
+            Err(Error::DateParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn parse_date_rejects_invalid_day() {
+        assert!(matches!(
+            TDate::parse("31.02.2024"),
+            Err(Error::DateParsingError(_))
+        ));
+    }
+
+    fn image(copyright: Option<&str>) -> Image {
+        Image {
+            title: None,
+            copyright: copyright.map(str::to_string),
+            alttext: None,
+            image_variants: None,
+            kind: "image".to_string(),
+        }
+    }
+
+    #[test]
+    fn credit_with_agency_and_photographer() {
+        let credit = image(Some("dpa | Foto: Max Mustermann")).credit().unwrap();
+
+        assert_eq!(credit.agency(), Some("dpa"));
+        assert_eq!(credit.photographer(), Some("Max Mustermann"));
+    }
+
+    #[test]
+    fn credit_with_photographer_only() {
+        let credit = image(Some("Foto: Max Mustermann")).credit().unwrap();
+
+        assert_eq!(credit.agency(), None);
+        assert_eq!(credit.photographer(), Some("Max Mustermann"));
+    }
+
+    #[test]
+    fn credit_without_conventions() {
+        let credit = image(Some("picture alliance")).credit().unwrap();
+
+        assert_eq!(credit.agency(), Some("picture alliance"));
+        assert_eq!(credit.photographer(), None);
+    }
+
+    #[test]
+    fn credit_of_empty_copyright() {
+        assert_eq!(image(Some("  ")).credit(), None);
+        assert_eq!(image(None).credit(), None);
+    }
+
+    #[test]
+    fn iso_week_53() {
+        let range = DateRange::from_iso_week(2020, 53).unwrap();
+
+        let mut dates: Vec<TDate> = range.dates.into_iter().collect();
+        dates.sort();
+
+        assert_eq!(dates.len(), 7);
+        assert_eq!(
+            dates.first(),
+            Some(&TDate::from_calendar_date(2020, Month::December, 28).unwrap())
+        );
+        assert_eq!(
+            dates.last(),
+            Some(&TDate::from_calendar_date(2021, Month::January, 3).unwrap())
+        );
+    }
+
+    #[test]
+    fn iso_week_53_of_52_week_year() {
+        assert!(matches!(
+            DateRange::from_iso_week(2021, 53),
+            Err(Error::DateParsingError(_))
+        ));
+    }
+
+    fn endpoint_url(endpoint: &str) -> String {
+        let date = TDate::from_calendar_date(2023, Month::March, 14).unwrap();
+
+        TRequestBuilder::new()
+            .endpoint(endpoint)
+            .prepare_url(date)
+            .unwrap()
+    }
+
+    #[test]
+    fn prepare_url_relative_endpoint() {
+        assert_eq!(
+            endpoint_url("api2u/news"),
+            "https://www.tagesschau.de/api2u/news?date=230314"
+        );
+    }
+
+    #[test]
+    fn prepare_url_relative_endpoint_with_trailing_slash() {
+        assert_eq!(
+            endpoint_url("api2u/news/"),
+            "https://www.tagesschau.de/api2u/news/?date=230314"
+        );
+    }
+
+    #[test]
+    fn prepare_url_absolute_path_endpoint() {
+        assert_eq!(
+            endpoint_url("/api2u/news"),
+            "https://www.tagesschau.de/api2u/news?date=230314"
+        );
+    }
+
+    #[test]
+    fn prepare_url_mirror_endpoint() {
+        assert_eq!(
+            endpoint_url("https://mirror.example.org/tagesschau/api2u/news"),
+            "https://mirror.example.org/tagesschau/api2u/news?date=230314"
+        );
+    }
+}